@@ -9,9 +9,10 @@
 
 use std::time::Duration;
 
-use parse_duration::parse as parse_duration;
 use structopt::StructOpt;
 
+use ore::units::parse_duration;
+
 pub static KAFKA_SOURCE_NAME: &str = "billing_source";
 pub static CSV_SOURCE_NAME: &str = "price_source";
 
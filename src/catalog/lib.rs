@@ -28,6 +28,34 @@ pub mod names;
 
 pub mod sql;
 
+/// The error returned when a database, schema, or catalog item cannot be
+/// created because an object of the same kind and name already exists.
+///
+/// Callers implementing `IF NOT EXISTS` should downcast to this type rather
+/// than treating every error as a name collision.
+#[derive(Debug)]
+pub struct AlreadyExistsError {
+    kind: &'static str,
+    name: String,
+}
+
+impl AlreadyExistsError {
+    pub(crate) fn new(kind: &'static str, name: &str) -> AlreadyExistsError {
+        AlreadyExistsError {
+            kind,
+            name: name.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for AlreadyExistsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} '{}' already exists", self.kind, self.name)
+    }
+}
+
+impl std::error::Error for AlreadyExistsError {}
+
 /// A `Catalog` keeps track of the SQL objects known to the planner.
 ///
 /// For each object, it keeps track of both forward and reverse dependencies:
@@ -568,7 +596,7 @@ impl Catalog {
                     database_name,
                     schema_name,
                 } => {
-                    if schema_name.starts_with("mz_") || schema_name.starts_with("pg_") {
+                    if names::is_reserved_name(&schema_name) {
                         bail!("unacceptable schema name '{}'", schema_name);
                     }
                     let (database_id, database_name) = match database_name {
@@ -584,6 +612,12 @@ impl Catalog {
                     }
                 }
                 Op::CreateItem { id, name, item } => {
+                    if names::is_reserved_name(&name.item) {
+                        bail!(
+                            "unacceptable name '{}': the mz_ and pg_ prefixes are reserved",
+                            name.item
+                        );
+                    }
                     let database_id = match &name.database {
                         DatabaseSpecifier::Name(name) => tx.load_database_id(&name)?,
                         DatabaseSpecifier::Ambient => {
@@ -9,6 +9,19 @@
 
 use std::fmt;
 
+/// Reports whether `name` is reserved for use by the system.
+///
+/// Schema and item names that begin with `mz_` or `pg_` are reserved, so that
+/// future versions can introduce new builtin schemas and items without
+/// colliding with objects that users have already created.
+///
+/// Items that were created before item names were reserved are still loaded.
+/// Renaming any such item that collides with a newly introduced builtin is not
+/// handled here.
+pub fn is_reserved_name(name: &str) -> bool {
+    name.starts_with("mz_") || name.starts_with("pg_")
+}
+
 /// The fully-qualified name of an item in the catalog.
 ///
 /// Catalog names compare case sensitively. Normalization is the responsibility
@@ -17,6 +17,7 @@ use serde::{Deserialize, Serialize};
 use expr::GlobalId;
 
 use crate::names::{DatabaseSpecifier, FullName};
+use crate::AlreadyExistsError;
 
 const APPLICATION_ID: i32 = 0x1854_47dc;
 
@@ -220,7 +221,7 @@ impl Transaction<'_> {
         {
             Ok(_) => Ok(self.inner.last_insert_rowid()),
             Err(err) if is_constraint_violation(&err) => {
-                bail!("database '{}' already exists", database_name);
+                return Err(AlreadyExistsError::new("database", database_name).into());
             }
             Err(err) => Err(err.into()),
         }
@@ -238,7 +239,7 @@ impl Transaction<'_> {
         {
             Ok(_) => Ok(self.inner.last_insert_rowid()),
             Err(err) if is_constraint_violation(&err) => {
-                bail!("schema '{}' already exists", schema_name);
+                return Err(AlreadyExistsError::new("schema", schema_name).into());
             }
             Err(err) => Err(err.into()),
         }
@@ -260,7 +261,7 @@ impl Transaction<'_> {
         {
             Ok(_) => Ok(()),
             Err(err) if is_constraint_violation(&err) => {
-                bail!("catalog item '{}' already exists", item_name);
+                return Err(AlreadyExistsError::new("catalog item", item_name).into());
             }
             Err(err) => Err(err.into()),
         }
@@ -12,7 +12,7 @@ use std::future::Future;
 use std::pin::Pin;
 
 use dataflow_types::{PeekResponse, Update};
-use sql::Session;
+use sql::{DiscardTarget, Session};
 
 /// The requests the client can make of a [`Coordinator`](crate::Coordinator).
 #[derive(Debug)]
@@ -59,6 +59,56 @@ pub struct Response<T> {
 
 pub type RowsFuture = Pin<Box<dyn Future<Output = Result<PeekResponse, comm::Error>> + Send>>;
 
+/// The error returned when a statement that would write data or modify the
+/// catalog is executed against a coordinator running in read-only mode.
+#[derive(Debug)]
+pub struct ReadOnlyError;
+
+impl fmt::Display for ReadOnlyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("cannot execute statement: server is in read-only mode")
+    }
+}
+
+impl std::error::Error for ReadOnlyError {}
+
+/// The error returned when a statement that would write data or modify the
+/// catalog is executed while the coordinator is in maintenance mode.
+#[derive(Debug)]
+pub struct MaintenanceModeError {
+    /// The message that maintenance mode was entered with.
+    pub message: String,
+}
+
+impl fmt::Display for MaintenanceModeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "system is in maintenance mode: {}", self.message)
+    }
+}
+
+impl std::error::Error for MaintenanceModeError {}
+
+/// The error returned when a session attempts an operation that is reserved
+/// for the system user.
+#[derive(Debug)]
+pub struct PrivilegeError {
+    /// A description of the operation that was attempted.
+    pub action: &'static str,
+}
+
+impl fmt::Display for PrivilegeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "permission denied to {}: must be connected as {}",
+            self.action,
+            sql::SYSTEM_USER
+        )
+    }
+}
+
+impl std::error::Error for PrivilegeError {}
+
 #[derive(Debug)]
 pub enum StartupMessage {
     UnknownSessionDatabase,
@@ -66,6 +116,8 @@ pub enum StartupMessage {
 
 /// Response from the queue to an `Execute` command.
 pub enum ExecuteResponse {
+    /// A system configuration parameter was changed by ALTER SYSTEM.
+    AlteredSystemConfiguration,
     /// The current session has been taken out of transaction mode by COMMIT
     Commit,
     CreatedDatabase {
@@ -86,8 +138,11 @@ pub enum ExecuteResponse {
     CreatedTable {
         existed: bool,
     },
-    CreatedView,
+    CreatedView {
+        existed: bool,
+    },
     Deleted(usize),
+    Discarded(DiscardTarget),
     DroppedDatabase,
     DroppedSchema,
     DroppedSource,
@@ -114,6 +169,9 @@ pub enum ExecuteResponse {
 impl fmt::Debug for ExecuteResponse {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            ExecuteResponse::AlteredSystemConfiguration => {
+                f.write_str("ExecuteResponse::AlteredSystemConfiguration")
+            }
             ExecuteResponse::CreatedDatabase { existed } => write!(
                 f,
                 "ExecuteResponse::CreatedDatabase {{ existed: {} }}",
@@ -142,8 +200,13 @@ impl fmt::Debug for ExecuteResponse {
                 "ExecuteResponse::CreatedTable {{ existed: {} }}",
                 existed
             ),
-            ExecuteResponse::CreatedView => f.write_str("ExecuteResponse::CreatedView"),
+            ExecuteResponse::CreatedView { existed } => {
+                write!(f, "ExecuteResponse::CreatedView {{ existed: {} }}", existed)
+            }
             ExecuteResponse::Deleted(n) => write!(f, "ExecuteResponse::Deleted({})", n),
+            ExecuteResponse::Discarded(target) => {
+                write!(f, "ExecuteResponse::Discarded({})", target)
+            }
             ExecuteResponse::DroppedDatabase => f.write_str("ExecuteResponse::DroppedDatabase"),
             ExecuteResponse::DroppedSchema => f.write_str("ExecuteResponse::DroppedSchema"),
             ExecuteResponse::DroppedIndex => f.write_str("ExecuteResponse::DroppedIndex"),
@@ -47,12 +47,15 @@ use futures::Stream;
 use ore::thread::JoinHandleExt;
 use ore::{collections::CollectionExt, future::MaybeFuture};
 use repr::{ColumnName, Datum, RelationDesc, RelationType, Row};
-use sql::{MutationKind, ObjectType, Plan, Session};
+use sql::{DiscardTarget, MutationKind, ObjectType, Plan, Session, TransactionStatus};
 use sql::{Params, PreparedStatement};
 
 use crate::persistence::SqlSerializer;
 use crate::timestamp::{TimestampChannel, TimestampConfig, TimestampMessage, Timestamper};
-use crate::{Command, ExecuteResponse, Response, StartupMessage};
+use crate::{
+    Command, ExecuteResponse, MaintenanceModeError, PrivilegeError, ReadOnlyError, Response,
+    StartupMessage,
+};
 
 type ClientTx = futures::channel::oneshot::Sender<Response<ExecuteResponse>>;
 
@@ -74,6 +77,9 @@ where
     pub data_directory: Option<&'a Path>,
     pub executor: &'a tokio::runtime::Handle,
     pub timestamp: Option<TimestampConfig>,
+    /// Whether to serve only queries that neither write data nor modify the
+    /// catalog. An existing catalog is required, and sinks are not started.
+    pub read_only: bool,
 }
 
 /// Glues the external world to the Timely workers.
@@ -103,6 +109,11 @@ where
     log: bool,
     executor: Option<tokio::runtime::Handle>,
     feedback_rx: Option<comm::mpsc::Receiver<WorkerFeedbackWithMeta>>,
+    /// The message to reject DDL and writes with, if the system is in
+    /// maintenance mode.
+    maintenance_message: Option<String>,
+    /// Whether writes and DDL are permanently rejected.
+    read_only: bool,
 }
 
 impl<C> Coordinator<C>
@@ -132,6 +143,15 @@ where
         let mut optimizer = Optimizer::default();
 
         let catalog_path = catalog_path.as_deref();
+        if config.read_only {
+            match catalog_path {
+                Some(path) if !path.exists() => bail!(
+                    "read-only mode requires an existing catalog, but {} does not exist",
+                    path.display()
+                ),
+                _ => (),
+            }
+        }
         let catalog = if let Some(logging_config) = config.logging {
             Catalog::open::<SqlSerializer, _>(catalog_path, |catalog| {
                 for log_src in logging_config.active_logs() {
@@ -193,6 +213,7 @@ where
                             view,
                             replace,
                             materialize,
+                            if_not_exists: _,
                         }))) => {
                             assert!(replace.is_none());
                             assert!(materialize);
@@ -258,6 +279,8 @@ where
                 executor: Some(config.executor.clone()),
                 timestamp_config: config.timestamp,
                 feedback_rx: Some(rx),
+                maintenance_message: None,
+                read_only: config.read_only,
             };
 
             let catalog_entries: Vec<_> = coord
@@ -279,7 +302,11 @@ where
                         coord.insert_view(id, &view);
                     }
                     CatalogItem::Sink(sink) => {
-                        coord.create_sink_dataflow(name.to_string(), id, sink);
+                        // A read-only server must not produce output that
+                        // would duplicate that of the primary.
+                        if !coord.read_only {
+                            coord.create_sink_dataflow(name.to_string(), id, sink);
+                        }
                     }
                     CatalogItem::Index(index) => match id {
                         GlobalId::User(_) => {
@@ -549,6 +576,18 @@ where
         plan: Plan,
         conn_id: u32,
     ) -> Result<ExecuteResponse, failure::Error> {
+        if self.read_only && plan.mutates() {
+            return Err(ReadOnlyError.into());
+        }
+        if let Some(message) = &self.maintenance_message {
+            if plan.mutates() {
+                return Err(MaintenanceModeError {
+                    message: message.clone(),
+                }
+                .into());
+            }
+        }
+
         match plan {
             Plan::CreateDatabase {
                 name,
@@ -563,7 +602,7 @@ where
                 ];
                 match self.catalog_transact(ops) {
                     Ok(_) => Ok(ExecuteResponse::CreatedDatabase { existed: false }),
-                    Err(_) if if_not_exists => {
+                    Err(err) if if_not_exists && is_already_exists(&err) => {
                         Ok(ExecuteResponse::CreatedDatabase { existed: true })
                     }
                     Err(err) => Err(err),
@@ -581,7 +620,9 @@ where
                 };
                 match self.catalog_transact(vec![op]) {
                     Ok(_) => Ok(ExecuteResponse::CreatedSchema { existed: false }),
-                    Err(_) if if_not_exists => Ok(ExecuteResponse::CreatedSchema { existed: true }),
+                    Err(err) if if_not_exists && is_already_exists(&err) => {
+                        Ok(ExecuteResponse::CreatedSchema { existed: true })
+                    }
                     Err(err) => Err(err),
                 }
             }
@@ -636,7 +677,9 @@ where
                         self.insert_index(index_id, &index, None);
                         Ok(ExecuteResponse::CreatedTable { existed: false })
                     }
-                    Err(_) if if_not_exists => Ok(ExecuteResponse::CreatedTable { existed: true }),
+                    Err(err) if if_not_exists && is_already_exists(&err) => {
+                        Ok(ExecuteResponse::CreatedTable { existed: true })
+                    }
                     Err(err) => Err(err),
                 }
             }
@@ -692,7 +735,9 @@ where
                         }
                         Ok(ExecuteResponse::CreatedSource { existed: false })
                     }
-                    Err(_) if if_not_exists => Ok(ExecuteResponse::CreatedSource { existed: true }),
+                    Err(err) if if_not_exists && is_already_exists(&err) => {
+                        Ok(ExecuteResponse::CreatedSource { existed: true })
+                    }
                     Err(err) => Err(err),
                 }
             }
@@ -718,7 +763,9 @@ where
                         self.create_sink_dataflow(name.to_string(), id, sink);
                         Ok(ExecuteResponse::CreatedSink { existed: false })
                     }
-                    Err(_) if if_not_exists => Ok(ExecuteResponse::CreatedSink { existed: true }),
+                    Err(err) if if_not_exists && is_already_exists(&err) => {
+                        Ok(ExecuteResponse::CreatedSink { existed: true })
+                    }
                     Err(err) => Err(err),
                 }
             }
@@ -728,12 +775,17 @@ where
                 view,
                 replace,
                 materialize,
+                if_not_exists,
             } => {
+                // Skip an existing view before doing any work that could fail
+                // on its own, like checking for cross joins.
+                if if_not_exists && self.catalog.try_get(&name).is_some() {
+                    return Ok(ExecuteResponse::CreatedView { existed: true });
+                }
                 let mut ops = vec![];
                 if let Some(id) = replace {
                     ops.extend(self.catalog.drop_items_ops(&[id]));
                 }
-                let view_id = self.catalog.allocate_id()?;
                 let eval_env = EvalEnv::default();
                 let view = catalog::View {
                     create_sql: view.create_sql,
@@ -743,6 +795,10 @@ where
                     desc: view.desc,
                     eval_env,
                 };
+                if materialize && session.max_cross_join_rows() > 0 {
+                    check_cross_joins(view.expr.as_ref(), session.max_cross_join_rows() as u64)?;
+                }
+                let view_id = self.catalog.allocate_id()?;
                 ops.push(catalog::Op::CreateItem {
                     id: view_id,
                     name: name.clone(),
@@ -767,7 +823,13 @@ where
                 } else {
                     (None, None)
                 };
-                self.catalog_transact(ops)?;
+                match self.catalog_transact(ops) {
+                    Ok(()) => (),
+                    Err(err) if if_not_exists && is_already_exists(&err) => {
+                        return Ok(ExecuteResponse::CreatedView { existed: true })
+                    }
+                    Err(err) => return Err(err),
+                }
                 self.insert_view(view_id, &view);
                 if materialize {
                     let mut dataflow = DataflowDesc::new(name.to_string());
@@ -779,7 +841,7 @@ where
                         dataflow,
                     );
                 }
-                Ok(ExecuteResponse::CreatedView)
+                Ok(ExecuteResponse::CreatedView { existed: false })
             }
 
             Plan::CreateIndex {
@@ -787,12 +849,23 @@ where
                 index,
                 if_not_exists,
             } => {
+                if if_not_exists && self.catalog.try_get(&name).is_some() {
+                    return Ok(ExecuteResponse::CreatedIndex { existed: true });
+                }
                 let index = catalog::Index {
                     create_sql: index.create_sql,
                     keys: index.keys,
                     on: index.on,
                     eval_env: EvalEnv::default(),
                 };
+                if session.max_cross_join_rows() > 0 {
+                    if let CatalogItem::View(view) = self.catalog.get_by_id(&index.on).item() {
+                        check_cross_joins(
+                            view.expr.as_ref(),
+                            session.max_cross_join_rows() as u64,
+                        )?;
+                    }
+                }
                 let id = self.catalog.allocate_id()?;
                 let op = catalog::Op::CreateItem {
                     id,
@@ -804,7 +877,9 @@ where
                         self.create_index_dataflow(name.to_string(), id, index);
                         Ok(ExecuteResponse::CreatedIndex { existed: false })
                     }
-                    Err(_) if if_not_exists => Ok(ExecuteResponse::CreatedIndex { existed: true }),
+                    Err(err) if if_not_exists && is_already_exists(&err) => {
+                        Ok(ExecuteResponse::CreatedIndex { existed: true })
+                    }
                     Err(err) => Err(err),
                 }
             }
@@ -879,6 +954,27 @@ where
                 Ok(ExecuteResponse::Rollback)
             }
 
+            Plan::Discard(target) => {
+                if let DiscardTarget::All = target {
+                    if *session.transaction() != TransactionStatus::Idle {
+                        bail!("DISCARD ALL cannot run inside a transaction block");
+                    }
+                    session.reset();
+                }
+                Ok(ExecuteResponse::Discarded(target))
+            }
+
+            Plan::SetMaintenanceMode { message } => {
+                if !session.is_system_user() {
+                    return Err(PrivilegeError {
+                        action: "set maintenance_mode",
+                    }
+                    .into());
+                }
+                self.maintenance_message = message;
+                Ok(ExecuteResponse::AlteredSystemConfiguration)
+            }
+
             Plan::Peek {
                 source,
                 when,
@@ -898,6 +994,10 @@ where
                     .optimizer
                     .optimize(source, self.catalog.indexes(), &eval_env);
 
+                if session.max_cross_join_rows() > 0 {
+                    check_cross_joins(source.as_ref(), session.max_cross_join_rows() as u64)?;
+                }
+
                 // If this optimizes to a constant expression, we can immediately return the result.
                 if let RelationExpr::Constant { rows, typ: _ } = source.as_ref() {
                     let mut results = Vec::new();
@@ -1041,6 +1141,14 @@ where
                 } else {
                     bail!("Cannot tail a view that has not been materialized.")
                 };
+                if session.max_cross_join_rows() > 0 {
+                    if let CatalogItem::View(view) = self.catalog.get_by_id(&source_id).item() {
+                        check_cross_joins(
+                            view.expr.as_ref(),
+                            session.max_cross_join_rows() as u64,
+                        )?;
+                    }
+                }
 
                 let sink_name = format!(
                     "tail-source-{}",
@@ -1068,14 +1176,20 @@ where
 
             Plan::SendRows(rows) => Ok(send_immediate_rows(rows)),
 
-            Plan::ExplainPlan(relation_expr) => {
+            Plan::ExplainPlan {
+                relation_expr,
+                redacted,
+            } => {
                 let eval_env = EvalEnv {
                     wall_time: Some(chrono::Utc::now()),
                     logical_time: Some(0),
                 };
-                let relation_expr =
+                let mut relation_expr =
                     self.optimizer
                         .optimize(relation_expr, self.catalog.indexes(), &eval_env);
+                if redacted {
+                    relation_expr.as_mut().redact_literals();
+                }
                 let pretty = relation_expr.as_ref().pretty_humanized(&self.catalog);
                 let rows = vec![Row::pack(&[Datum::from(&*pretty)])];
                 Ok(send_immediate_rows(rows))
@@ -1842,11 +1956,75 @@ where
     }
 }
 
+/// Reports whether `err` indicates that an object could not be created because
+/// one with the same name already exists, as opposed to any other failure.
+fn is_already_exists(err: &failure::Error) -> bool {
+    err.downcast_ref::<catalog::AlreadyExistsError>().is_some()
+}
+
 fn broadcast(tx: &mut comm::broadcast::Sender<SequencedCommand>, cmd: SequencedCommand) {
     // TODO(benesch): avoid flushing after every send.
     block_on(tx.send(cmd)).unwrap();
 }
 
+/// Returns an error if `expr` contains a cross join that may produce more than
+/// `limit` rows.
+///
+/// There are no statistics about the size of collections, so the check relies
+/// on [`RelationExpr::max_rows`]. The inputs of each join are grouped into
+/// components connected by the join's equivalences. A join with more than one
+/// component is a cross join, and it is rejected if more than one component
+/// is unbounded, or if the product of the bounded components exceeds `limit`.
+/// Crossing a single unbounded component with small bounded ones, as happens
+/// with uncorrelated scalar subqueries, is permitted.
+fn check_cross_joins(expr: &RelationExpr, limit: u64) -> Result<(), failure::Error> {
+    let mut violation = false;
+    expr.visit(&mut |e| {
+        if let RelationExpr::Join {
+            inputs, variables, ..
+        } = e
+        {
+            let mut components = (0..inputs.len()).collect::<Vec<_>>();
+            for variable in variables {
+                let merged = variable
+                    .iter()
+                    .map(|(input, _column)| components[*input])
+                    .collect::<Vec<_>>();
+                if let Some(min) = merged.iter().min().cloned() {
+                    for component in components.iter_mut() {
+                        if merged.contains(component) {
+                            *component = min;
+                        }
+                    }
+                }
+            }
+            let mut bounds = BTreeMap::new();
+            for (input, component) in inputs.iter().zip(components) {
+                let bound = bounds.entry(component).or_insert(Some(1u64));
+                *bound = bound.and_then(|b| input.max_rows().and_then(|n| b.checked_mul(n)));
+            }
+            if bounds.len() > 1 {
+                let unbounded = bounds.values().filter(|b| b.is_none()).count();
+                let product = bounds
+                    .values()
+                    .flatten()
+                    .try_fold(1u64, |acc, b| acc.checked_mul(*b));
+                if unbounded > 1 || product.map_or(true, |p| p > limit) {
+                    violation = true;
+                }
+            }
+        }
+    });
+    if violation {
+        bail!(
+            "query contains a cross join that may produce more than {} rows; \
+             add a join condition or raise max_cross_join_rows",
+            limit
+        );
+    }
+    Ok(())
+}
+
 /// Constructs an [`ExecuteResponse`] that that will send some rows to the
 /// client immediately, as opposed to asking the dataflow layer to send along
 /// the rows after some computation.
@@ -2037,6 +2215,7 @@ pub fn dump_catalog(data_directory: &Path) -> Result<String, failure::Error> {
         logging: Some(&LoggingConfig::new(Duration::from_secs(0))),
         executor: runtime.handle(),
         timestamp: None,
+        read_only: false,
     })?;
     Ok(coord.catalog.dump())
 }
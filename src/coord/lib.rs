@@ -27,4 +27,7 @@ mod timestamp;
 
 pub use self::coord::{dump_catalog, Config, Coordinator};
 pub use self::timestamp::TimestampConfig;
-pub use command::{Command, ExecuteResponse, Response, RowsFuture, StartupMessage};
+pub use command::{
+    Command, ExecuteResponse, MaintenanceModeError, PrivilegeError, ReadOnlyError, Response,
+    RowsFuture, StartupMessage,
+};
@@ -36,6 +36,7 @@ fn no_block() {
         data_directory: None,
         executor: &executor,
         timestamp: None,
+        read_only: false,
     })
     .unwrap();
 
@@ -18,6 +18,7 @@ use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
+use std::time::Duration;
 use url::Url;
 
 use expr::{
@@ -142,8 +143,21 @@ impl RowSetFinishing {
             }
             rows.sort_by(&mut sort_by);
             for row in rows {
-                let datums = row.unpack();
-                let new_row = Row::pack(self.project.iter().map(|i| &datums[*i]));
+                // Walk the row with a single iterator, skipping unprojected
+                // datums without decoding them, and only restart from the
+                // beginning when the projection moves backwards. This avoids
+                // allocating any intermediate buffers per row.
+                let mut iter = row.iter();
+                let mut next = 0;
+                let new_row = Row::pack(self.project.iter().map(|&c| {
+                    if c < next {
+                        iter = row.iter();
+                        next = 0;
+                    }
+                    let datum = iter.nth(c - next).expect("projected column out of bounds");
+                    next = c + 1;
+                    datum
+                }));
                 *row = new_row;
             }
         }
@@ -324,6 +338,7 @@ pub enum DataEncoding {
     },
     Protobuf(ProtobufEncoding),
     Bytes,
+    Json,
     Text,
 }
 
@@ -408,6 +423,13 @@ pub struct KafkaSourceConnector {
     pub url: Url,
     pub topic: String,
     pub ssl_certificate_file: Option<PathBuf>,
+    /// The size in bytes above which message payloads are dropped rather than
+    /// decoded, if any.
+    pub max_message_size: Option<u64>,
+    /// Whether the source asserts that it never retracts records. Messages
+    /// whose envelope would produce a retraction, like Debezium updates and
+    /// deletes, are rejected in their entirety and logged.
+    pub append_only: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -435,6 +457,18 @@ pub struct KafkaSinkConnector {
     pub url: Url,
     pub topic: String,
     pub schema_registry_url: Url,
+    /// The producer's compression codec, if not the librdkafka default.
+    pub compression: Option<String>,
+    /// The maximum number of messages in a produced batch, if not the
+    /// librdkafka default.
+    pub batch_size: Option<u32>,
+    /// How long the producer waits to fill a batch, if not the librdkafka
+    /// default.
+    pub linger: Option<Duration>,
+    /// Whether every source upstream of the sink assigns real-time
+    /// timestamps, i.e., milliseconds since the Unix epoch. Emit latency is
+    /// only meaningful if so.
+    pub real_time: bool,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -22,10 +22,13 @@ pub fn avro<G>(
     stream: &Stream<G, (Vec<u8>, Option<i64>)>,
     raw_schema: &str,
     schema_registry: Option<Url>,
+    name: &str,
+    append_only: bool,
 ) -> Stream<G, (Row, Timestamp, Diff)>
 where
     G: Scope<Timestamp = Timestamp>,
 {
+    let name = name.to_owned();
     stream.unary(
         Exchange::new(|x: &(Vec<u8>, _)| x.0.hashed()),
         "AvroDecode",
@@ -34,8 +37,22 @@ where
             move |input, output| {
                 input.for_each(|cap, data| {
                     let mut session = output.session(&cap);
-                    for (payload, _) in data.iter() {
+                    for (payload, offset) in data.iter() {
                         match decoder.decode(payload) {
+                            // A Debezium update or delete retracts the old
+                            // version of the record, which an append-only
+                            // source promises never to do. Reject the whole
+                            // message, rather than just the retraction, so
+                            // that an update does not turn into an insert.
+                            Ok(diff_pair) if append_only && diff_pair.before.is_some() => {
+                                EVENTS_COUNTER.avro.error.inc();
+                                error!(
+                                    "{}: rejected message at offset {} with a retraction from \
+                                     append-only source",
+                                    name,
+                                    offset.unwrap_or(-1)
+                                );
+                            }
                             Ok(diff_pair) => {
                                 EVENTS_COUNTER.avro.success.inc();
                                 if let Some(before) = diff_pair.before {
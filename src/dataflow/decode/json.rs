@@ -0,0 +1,61 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use differential_dataflow::Hashable;
+use log::error;
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::operators::Operator;
+use timely::dataflow::{Scope, Stream};
+
+use super::EVENTS_COUNTER;
+use dataflow_types::{Diff, Timestamp};
+use repr::jsonb::Jsonb;
+use repr::{Datum, Row, RowPacker};
+
+/// Decodes each line of the input as a JSON document, producing a `jsonb`
+/// column and the line number. Lines that are not valid UTF-8 or not valid
+/// JSON are logged and skipped.
+pub fn json<G>(stream: &Stream<G, (Vec<u8>, Option<i64>)>) -> Stream<G, (Row, Timestamp, Diff)>
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    stream.unary(
+        Exchange::new(|x: &(Vec<u8>, _)| x.0.hashed()),
+        "JsonDecode",
+        |_, _| {
+            move |input, output| {
+                input.for_each(|cap, lines| {
+                    let mut session = output.session(&cap);
+                    for (line, line_no) in &*lines {
+                        let jsonb = match std::str::from_utf8(line) {
+                            Ok(line) => line.parse::<Jsonb>(),
+                            Err(e) => Err(e.into()),
+                        };
+                        let jsonb = match jsonb {
+                            Ok(jsonb) => jsonb,
+                            Err(e) => {
+                                EVENTS_COUNTER.json.error.inc();
+                                error!(
+                                    "JSON error on line {}: {}. Ignoring row.",
+                                    line_no.map_or("?".into(), |n| n.to_string()),
+                                    e
+                                );
+                                continue;
+                            }
+                        };
+                        EVENTS_COUNTER.json.success.inc();
+                        let mut packer = jsonb.pack_into(RowPacker::new());
+                        packer.push(line_no.map(Datum::Int64).into());
+                        session.give((packer.finish(), *cap.time(), 1));
+                    }
+                });
+            }
+        },
+    )
+}
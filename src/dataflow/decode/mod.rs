@@ -24,10 +24,12 @@ use repr::Row;
 
 mod avro;
 mod csv;
+mod json;
 mod protobuf;
 mod regex;
 
 use self::csv::csv;
+use self::json::json;
 use self::regex::regex as regex_fn;
 use avro::avro;
 use protobuf::protobuf;
@@ -35,7 +37,7 @@ use std::iter;
 
 make_static_metric! {
     struct EventsRead: IntCounter {
-        "format" => { avro, csv, protobuf },
+        "format" => { avro, csv, json, protobuf },
         "status" => { success, error }
     }
 }
@@ -81,16 +83,24 @@ pub fn decode<G>(
     stream: &Stream<G, (Vec<u8>, Option<i64>)>,
     encoding: DataEncoding,
     name: &str,
+    append_only: bool,
 ) -> Stream<G, (Row, Timestamp, Diff)>
 where
     G: Scope<Timestamp = Timestamp>,
 {
     match encoding {
         DataEncoding::Csv(enc) => csv(stream, enc.n_cols, enc.delimiter),
-        DataEncoding::Avro(enc) => avro(stream, &enc.raw_schema, enc.schema_registry_url),
+        DataEncoding::Avro(enc) => avro(
+            stream,
+            &enc.raw_schema,
+            enc.schema_registry_url,
+            name,
+            append_only,
+        ),
         DataEncoding::Regex { regex } => regex_fn(stream, regex, name),
         DataEncoding::Protobuf(enc) => protobuf(stream, &enc.descriptors, &enc.message_name),
         DataEncoding::Bytes => raw(stream),
+        DataEncoding::Json => json(stream),
         DataEncoding::Text => raw(stream).map(|(row, r, d)| {
             let datums = row.unpack();
             (
@@ -142,6 +142,10 @@ pub(crate) fn build_dataflow<A: Allocate>(
                         sid: src_id.sid,
                         vid: first_export_id,
                     };
+                    let append_only = match &connector {
+                        ExternalSourceConnector::Kafka(c) => c.append_only,
+                        _ => false,
+                    };
                     let (source, capability) = match connector {
                         ExternalSourceConnector::Kafka(c) => {
                             // Distribute read responsibility among workers.
@@ -182,7 +186,7 @@ pub(crate) fn build_dataflow<A: Allocate>(
 
                     // TODO(brennan) -- this should just be a RelationExpr::FlatMap using regexp_extract, csv_extract,
                     // a hypothetical future avro_extract, protobuf_extract, etc.
-                    let stream = decode(&source, encoding, &dataflow.debug_name);
+                    let stream = decode(&source, encoding, &dataflow.debug_name, append_only);
 
                     let collection = match envelope {
                         Envelope::None => stream.as_collection(),
@@ -359,13 +363,16 @@ pub(crate) fn build_dataflow<A: Allocate>(
                     ))
                     .expect("No arrangements");
 
-                match sink.connector {
+                let sink_token: Box<dyn Any> = match sink.connector {
                     SinkConnector::Kafka(c) => {
                         sink::kafka(&collection.inner, sink_id, c, sink.from.1)
                     }
-                    SinkConnector::Tail(c) => sink::tail(&collection.inner, sink_id, c),
-                }
-                dataflow_drops.insert(sink_id, Box::new(tokens));
+                    SinkConnector::Tail(c) => {
+                        sink::tail(&collection.inner, sink_id, c);
+                        Box::new(())
+                    }
+                };
+                dataflow_drops.insert(sink_id, Box::new((tokens, sink_token)));
             }
         });
     })
@@ -398,6 +398,10 @@ where
             // nothing to do, it will park the thread. We rely on another thread
             // unparking us when there's new work to be done, e.g., when sending
             // a command or when new Kafka messages have arrived.
+            //
+            // Panics are deliberately not caught here. The other workers
+            // would stall waiting on this worker's progress, so a panicking
+            // operator takes down the whole process instead.
             self.inner.step_or_park(None);
 
             // Report frontier information back the coordinator.
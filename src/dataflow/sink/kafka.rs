@@ -7,7 +7,15 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::any::Any;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use lazy_static::lazy_static;
 use log::error;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec, HistogramVec,
+    IntCounterVec, IntGaugeVec,
+};
 use rdkafka::config::ClientConfig;
 use rdkafka::producer::FutureProducer;
 use rdkafka::producer::FutureRecord;
@@ -20,6 +28,49 @@ use expr::GlobalId;
 use interchange::avro::Encoder;
 use repr::{RelationDesc, Row};
 
+lazy_static! {
+    static ref QUEUE_DEPTH: IntGaugeVec = register_int_gauge_vec!(
+        "mz_kafka_sink_queue_depth",
+        "the number of messages awaiting delivery by a Kafka sink's producer",
+        &["sink_id", "worker"]
+    )
+    .unwrap();
+    static ref BYTES_ENCODED: IntCounterVec = register_int_counter_vec!(
+        "mz_kafka_sink_bytes_encoded_total",
+        "the number of bytes handed to a Kafka sink's producer, before compression",
+        &["sink_id", "worker"]
+    )
+    .unwrap();
+    static ref EMIT_LATENCY: HistogramVec = register_histogram_vec!(
+        "mz_kafka_sink_emit_latency_seconds",
+        "the delay between an update's timestamp and its hand-off to a Kafka sink's producer",
+        &["sink_id", "worker"],
+        prometheus::exponential_buckets(0.001, 2.0, 17).unwrap()
+    )
+    .unwrap();
+}
+
+/// Removes a worker's series for a sink from the per-sink metrics when
+/// dropped, so that dropped sinks do not linger in the exported metrics.
+///
+/// Each worker owns its own series, so one worker dropping its guard does not
+/// pull a series out from under another worker that is still writing to it.
+struct MetricsGuard {
+    sink_id: String,
+    worker: String,
+}
+
+impl Drop for MetricsGuard {
+    fn drop(&mut self) {
+        // The series are only created once the producer is set up, so they
+        // may not exist.
+        let labels = &[self.sink_id.as_str(), self.worker.as_str()];
+        let _ = QUEUE_DEPTH.remove_label_values(labels);
+        let _ = BYTES_ENCODED.remove_label_values(labels);
+        let _ = EMIT_LATENCY.remove_label_values(labels);
+    }
+}
+
 // TODO@jldlaughlin: What guarantess does this sink support? #1728
 
 // TODO@jldlaughlin: Progress tracking for kafka sinks #1442
@@ -47,14 +98,22 @@ use repr::{RelationDesc, Row};
 //            - NB: This, like other decisions we've made, assumes that
 //              the user has configured their Kafka instance to automatically
 //              create new topics.
+//
+// The returned token removes the sink's metrics when it is dropped.
 pub fn kafka<G>(
     stream: &Stream<G, (Row, Timestamp, Diff)>,
     id: GlobalId,
     connector: KafkaSinkConnector,
     relation_desc: RelationDesc,
-) where
+) -> Box<dyn Any>
+where
     G: Scope<Timestamp = Timestamp>,
 {
+    let worker = stream.scope().index().to_string();
+    let token = Box::new(MetricsGuard {
+        sink_id: id.to_string(),
+        worker: worker.clone(),
+    });
     let schema = interchange::avro::encode_schema(&relation_desc).expect("");
 
     // Send new schema to registry, get back the schema id for the sink.
@@ -64,20 +123,58 @@ pub fn kafka<G>(
         Ok(schema_id) => {
             let mut config = ClientConfig::new();
             config.set("bootstrap.servers", &connector.url.to_string());
-            let producer: FutureProducer = config.create().unwrap();
+            if let Some(compression) = &connector.compression {
+                config.set("compression.type", compression);
+            }
+            if let Some(batch_size) = connector.batch_size {
+                config.set("batch.num.messages", &batch_size.to_string());
+            }
+            if let Some(linger) = connector.linger {
+                config.set("queue.buffering.max.ms", &linger.as_millis().to_string());
+            }
+            let producer: FutureProducer = match config.create() {
+                Ok(producer) => producer,
+                Err(e) => {
+                    error!("unable to create producer in kafka sink: {}", e);
+                    return token;
+                }
+            };
 
+            // Every worker runs its own producer, so each reports its own
+            // series.
+            let sink_id = id.to_string();
+            let labels = &[sink_id.as_str(), worker.as_str()];
+            let queue_depth = QUEUE_DEPTH.with_label_values(labels);
+            let bytes_encoded = BYTES_ENCODED.with_label_values(labels);
+            let emit_latency = EMIT_LATENCY.with_label_values(labels);
             stream.sink(Pipeline, &format!("kafka-{}", id), move |input| {
                 let encoder = Encoder::new(&schema.to_string());
                 input.for_each(|_, rows| {
-                    for (row, _time, _diff) in rows.iter() {
+                    // Timestamps assigned by real-time sources are milliseconds
+                    // since the Unix epoch, so the distance between an update's
+                    // timestamp and the wall clock approximates how long the
+                    // update took to travel from ingestion to this sink.
+                    // Timestamps from BYO consistency sources carry no such
+                    // meaning, so no samples are taken for them.
+                    let now = SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .expect("System time seems to be before 1970.")
+                        .as_millis() as u64;
+                    for (row, time, _diff) in rows.iter() {
+                        if connector.real_time && *time <= now {
+                            emit_latency.observe((now - *time) as f64 / 1000.0);
+                        }
                         let buf = encoder.encode(schema_id, row);
+                        bytes_encoded.inc_by(buf.len() as i64);
                         let record: FutureRecord<&Vec<u8>, _> =
                             FutureRecord::to(&connector.topic).payload(&buf);
                         producer.send(record, 1000 /* block_ms */);
                     }
-                })
+                });
+                queue_depth.set(i64::from(producer.in_flight_count()));
             })
         }
         Err(e) => error!("unable to publish schema to registry in kafka sink: {}", e),
     }
+    token
 }
@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use std::cmp;
 use std::sync::Mutex;
 use std::time::Duration;
 
@@ -14,7 +15,7 @@ use crate::server::{TimestampChanges, TimestampHistories};
 use dataflow_types::{Consistency, KafkaSourceConnector, Timestamp};
 use lazy_static::lazy_static;
 use log::{error, warn};
-use prometheus::{register_int_counter, IntCounter};
+use prometheus::{register_int_counter, register_int_counter_vec, IntCounter, IntCounterVec};
 use rdkafka::consumer::{BaseConsumer, Consumer, ConsumerContext};
 use rdkafka::{ClientConfig, ClientContext};
 use rdkafka::{Message, Timestamp as KafkaTimestamp};
@@ -33,6 +34,12 @@ lazy_static! {
         "Count of kafka bytes we have read from the wire"
     )
     .unwrap();
+    static ref MESSAGES_DROPPED: IntCounterVec = register_int_counter_vec!(
+        "mz_kafka_messages_dropped_total",
+        "Count of kafka messages dropped for exceeding a source's max_message_size",
+        &["source_id"]
+    )
+    .unwrap();
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -54,6 +61,8 @@ where
         url,
         topic,
         ssl_certificate_file,
+        max_message_size,
+        append_only: _,
     } = connector.clone();
 
     let ts = if read_kafka {
@@ -68,6 +77,8 @@ where
         None
     };
 
+    let dropped = MESSAGES_DROPPED.with_label_values(&[&id.sid.to_string()]);
+
     let (stream, capability) = source(id, ts, scope, &name.clone(), move |info| {
         let activator = scope.activator_for(&info.address[..]);
 
@@ -84,6 +95,21 @@ where
             .set("enable.sparse.connections", "true")
             .set("bootstrap.servers", &url.to_string());
 
+        if let Some(max) = max_message_size {
+            // Size librdkafka's fetches to the largest message we are willing
+            // to decode, so that oversized messages are not buffered in memory
+            // only to be dropped. librdkafka requires message.max.bytes to be
+            // at least 1000, fetch.max.bytes to be at least
+            // message.max.bytes, and receive.message.max.bytes to exceed
+            // fetch.max.bytes by 512.
+            let fetch_max = cmp::max(max, 1000);
+            config
+                .set("fetch.message.max.bytes", &max.to_string())
+                .set("message.max.bytes", &fetch_max.to_string())
+                .set("fetch.max.bytes", &fetch_max.to_string())
+                .set("receive.message.max.bytes", &(fetch_max + 512).to_string());
+        }
+
         if let Some(path) = ssl_certificate_file {
             // See https://github.com/edenhill/librdkafka/wiki/Using-SSL-with-librdkafka
             // for more details on this librdkafka option
@@ -167,9 +193,17 @@ where
                                 last_processed_offset = offset;
 
                                 if let Some(payload) = payload {
-                                    let out = payload.to_vec();
-                                    BYTES_READ_COUNTER.inc_by(out.len() as i64);
-                                    output.session(&cap).give((out, Some(message.offset())));
+                                    if !exceeds_max_message_size(
+                                        &name,
+                                        offset,
+                                        payload.len(),
+                                        max_message_size,
+                                        &dropped,
+                                    ) {
+                                        let out = payload.to_vec();
+                                        BYTES_READ_COUNTER.inc_by(out.len() as i64);
+                                        output.session(&cap).give((out, Some(message.offset())));
+                                    }
                                 }
 
                                 downgrade_capability(
@@ -244,6 +278,15 @@ where
                                     );
                                 };
 
+                                if exceeds_max_message_size(
+                                    &name,
+                                    message.offset(),
+                                    payload.len(),
+                                    max_message_size,
+                                    &dropped,
+                                ) {
+                                    continue;
+                                }
                                 let out = payload.to_vec();
                                 BYTES_READ_COUNTER.inc_by(out.len() as i64);
                                 output.session(&cap).give((out, Some(message.offset())));
@@ -276,6 +319,28 @@ where
     }
 }
 
+/// Reports whether a message payload is too large to decode. If so, the
+/// message is counted in `dropped`, and an error that identifies it is logged.
+fn exceeds_max_message_size(
+    name: &str,
+    offset: i64,
+    len: usize,
+    max: Option<u64>,
+    dropped: &IntCounter,
+) -> bool {
+    match max {
+        Some(max) if len as u64 > max => {
+            dropped.inc();
+            error!(
+                "{}: dropped kafka message at offset {}: size {} bytes exceeds max_message_size of {} bytes",
+                name, offset, len, max
+            );
+            true
+        }
+        _ => false,
+    }
+}
+
 /// For a given offset, returns an option type returning the matching timestamp or None
 fn find_matching_timestamp(
     id: &SourceInstanceId,
@@ -18,6 +18,7 @@ use serde::{Deserialize, Serialize};
 use repr::decimal::Significand;
 use repr::{ColumnType, Datum, RelationType, Row, RowArena, ScalarType};
 
+use crate::scalar::REDACTED;
 use crate::EvalEnv;
 
 use std::iter;
@@ -690,6 +691,15 @@ impl UnaryTableFunc {
         })
     }
 
+    /// Replaces the regex pattern of a `RegexpExtract` with a placeholder,
+    /// keeping its capture groups so that the output arity is unchanged. See
+    /// [`RelationExpr::redact_literals`](crate::RelationExpr::redact_literals).
+    pub(crate) fn redact_literals(&mut self) {
+        if let UnaryTableFunc::RegexpExtract(a) = self {
+            a.0 = repr::regex::Regex(regex::Regex::new(&regex::escape(REDACTED)).unwrap());
+        }
+    }
+
     pub fn output_arity(&self) -> usize {
         match self {
             UnaryTableFunc::JsonbEach => 2,
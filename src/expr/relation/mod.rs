@@ -19,6 +19,7 @@ use repr::{ColumnType, Datum, RelationType, Row};
 
 use self::func::{AggregateFunc, UnaryTableFunc};
 use crate::pretty::{tighten_outputs, DocAllocatorExt, DocBuilderExt};
+use crate::scalar::REDACTED;
 use crate::{GlobalId, Id, IdHumanizer, LocalId, ScalarExpr};
 
 pub mod func;
@@ -610,6 +611,55 @@ impl RelationExpr {
         }
     }
 
+    /// Returns an upper bound on the number of rows this expression can
+    /// produce, if one can be determined without consulting any data.
+    ///
+    /// Only constant collections, global aggregations, and limits establish a
+    /// bound; anything that reads from a collection by identifier is assumed to
+    /// be unbounded.
+    pub fn max_rows(&self) -> Option<u64> {
+        match self {
+            RelationExpr::Constant { rows, .. } => {
+                Some(rows.iter().map(|(_row, diff)| diff.abs() as u64).sum())
+            }
+            RelationExpr::Get { .. } | RelationExpr::FlatMapUnary { .. } => None,
+            RelationExpr::Let { body, .. } => body.max_rows(),
+            RelationExpr::Project { input, .. }
+            | RelationExpr::Map { input, .. }
+            | RelationExpr::Filter { input, .. }
+            | RelationExpr::Negate { input }
+            | RelationExpr::Threshold { input }
+            | RelationExpr::ArrangeBy { input, .. } => input.max_rows(),
+            RelationExpr::Join { inputs, .. } => inputs.iter().try_fold(1u64, |acc, input| {
+                input.max_rows().and_then(|n| acc.checked_mul(n))
+            }),
+            RelationExpr::Reduce {
+                input, group_key, ..
+            } => {
+                if group_key.is_empty() {
+                    Some(1)
+                } else {
+                    input.max_rows()
+                }
+            }
+            RelationExpr::TopK {
+                input,
+                group_key,
+                limit,
+                ..
+            } => match (group_key.is_empty(), limit) {
+                (true, Some(limit)) => {
+                    let limit = *limit as u64;
+                    Some(input.max_rows().map_or(limit, |n| n.min(limit)))
+                }
+                _ => input.max_rows(),
+            },
+            RelationExpr::Union { left, right } => left
+                .max_rows()
+                .and_then(|l| right.max_rows().and_then(|r| l.checked_add(r))),
+        }
+    }
+
     /// Appends global identifiers on which this expression depends to `out`.
     pub fn global_uses(&self, out: &mut Vec<GlobalId>) {
         if let RelationExpr::Get {
@@ -1041,6 +1091,60 @@ impl RelationExpr {
         self.pretty_humanized(&crate::DummyHumanizer)
     }
 
+    /// Replaces every literal value in this expression, including the rows of
+    /// constant collections, with a placeholder, so that the expression can
+    /// be displayed without revealing the data it was built from. Structure,
+    /// column references, and object identifiers are left intact.
+    ///
+    /// The placeholders do not have the types of the values they replace, so
+    /// the resulting expression is only suitable for display.
+    ///
+    /// The `limit` and `offset` of a `TopK` are left as is. They are part of
+    /// the query's structure rather than values drawn from its data, and they
+    /// are stored as plain integers that have no room for a placeholder.
+    pub fn redact_literals(&mut self) {
+        self.visit_mut(&mut |e| match e {
+            RelationExpr::Constant { rows, .. } => {
+                for (row, _diff) in rows {
+                    let redacted = Row::pack(row.iter().map(|_| Datum::String(REDACTED)));
+                    *row = redacted;
+                }
+            }
+            RelationExpr::Map { scalars, .. } => {
+                for scalar in scalars {
+                    scalar.redact_literals();
+                }
+            }
+            RelationExpr::FlatMapUnary { func, expr, .. } => {
+                func.redact_literals();
+                expr.redact_literals();
+            }
+            RelationExpr::Filter { predicates, .. } => {
+                for predicate in predicates {
+                    predicate.redact_literals();
+                }
+            }
+            RelationExpr::Reduce {
+                group_key,
+                aggregates,
+                ..
+            } => {
+                for key in group_key {
+                    key.redact_literals();
+                }
+                for aggregate in aggregates {
+                    aggregate.expr.redact_literals();
+                }
+            }
+            RelationExpr::ArrangeBy { keys, .. } => {
+                for key in keys.iter_mut().flatten() {
+                    key.redact_literals();
+                }
+            }
+            _ => (),
+        })
+    }
+
     /// Take ownership of `self`, leaving an empty `RelationExpr::Constant` with the correct type.
     pub fn take_safely(&mut self) -> RelationExpr {
         let typ = self.typ();
@@ -1432,6 +1536,36 @@ Constant [[665]]"#
         );
     }
 
+    #[test]
+    fn test_max_rows() {
+        let get = RelationExpr::global_get(GlobalId::User(1), RelationType::new(vec![]));
+        assert_eq!(base().max_rows(), Some(0));
+        assert_eq!(constant(vec![vec![1], vec![2]]).max_rows(), Some(2));
+        assert_eq!(get.clone().max_rows(), None);
+        assert_eq!(get.clone().reduce(vec![], vec![]).max_rows(), Some(1));
+        assert_eq!(get.clone().reduce(vec![0], vec![]).max_rows(), None);
+        assert_eq!(
+            get.clone().top_k(vec![], vec![], Some(10), 0).max_rows(),
+            Some(10)
+        );
+        assert_eq!(
+            constant(vec![vec![1], vec![2]])
+                .product(constant(vec![vec![3], vec![4], vec![5]]))
+                .max_rows(),
+            Some(6)
+        );
+        assert_eq!(
+            constant(vec![vec![1]]).product(get.clone()).max_rows(),
+            None
+        );
+        assert_eq!(
+            constant(vec![vec![1]])
+                .union(constant(vec![vec![2]]))
+                .max_rows(),
+            Some(2)
+        );
+    }
+
     #[test]
     fn test_pretty_reduce() {
         let agg0 = AggregateExpr {
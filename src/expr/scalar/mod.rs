@@ -489,8 +489,28 @@ impl ScalarExpr {
         }
         .group()
     }
+
+    /// Replaces every literal value in this expression with a placeholder.
+    ///
+    /// The placeholders do not have the types of the values they replace, so
+    /// the resulting expression is only suitable for display. See
+    /// [`RelationExpr::redact_literals`](crate::RelationExpr::redact_literals).
+    pub fn redact_literals(&mut self) {
+        self.visit_mut(&mut |e| match e {
+            ScalarExpr::Literal(row, _typ) => *row = Row::pack(&[Datum::String(REDACTED)]),
+            ScalarExpr::CallUnary {
+                func: UnaryFunc::MatchRegex(regex),
+                ..
+            } => *regex = Regex(regex::Regex::new(&regex::escape(REDACTED)).unwrap()),
+            _ => (),
+        })
+    }
 }
 
+/// The placeholder that [`ScalarExpr::redact_literals`] substitutes for
+/// literal values.
+pub(crate) const REDACTED: &str = "<redacted>";
+
 /// An evaluation environment. Stores state that controls how certain
 /// expressions are evaluated.
 #[derive(Default, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash)]
@@ -593,4 +613,14 @@ mod tests {
 !(true && false)"
         );
     }
+
+    #[test]
+    fn test_redact_literals() {
+        let mut expr = ScalarExpr::Column(0).call_binary(
+            ScalarExpr::literal(Datum::Int64(42), ColumnType::new(ScalarType::Int64)),
+            BinaryFunc::AddInt64,
+        );
+        expr.redact_literals();
+        assert_eq!(expr.doc().pretty(72).to_string(), r#"#0 + "<redacted>""#);
+    }
 }
@@ -51,13 +51,13 @@ fn run() -> Result<(), failure::Error> {
     opts.optopt(
         "l",
         "logging-granularity",
-        "dataflow logging granularity (default 1s)",
+        "dataflow logging granularity, e.g. 500ms or 1s (default 1s)",
         "DURATION/\"off\"",
     );
     opts.optopt(
         "",
         "timestamp-frequency",
-        "timestamp advancement frequency (default 10ms)",
+        "timestamp advancement frequency, e.g. 10ms or 1s (default 10ms)",
         "DURATION/\"off\"",
     );
     opts.optopt(
@@ -98,6 +98,11 @@ fn run() -> Result<(), failure::Error> {
     );
     opts.optopt("", "symbiosis", "(internal use only)", "URL");
     opts.optflag("", "no-prometheus", "Do not gather prometheus metrics");
+    opts.optflag(
+        "",
+        "read-only",
+        "reject writes and DDL, serving only queries against an existing catalog",
+    );
 
     // Inform the user about what they are using, and how to contact us.
     beta_splash();
@@ -122,7 +127,7 @@ fn run() -> Result<(), failure::Error> {
     {
         None => Some(std::time::Duration::new(1, 0)),
         Some("off") => None,
-        Some(d) => Some(parse_duration::parse(&d)?),
+        Some(d) => Some(parse_duration_flag("logging-granularity", d)?),
     };
 
     let timestamp_frequency = match popts
@@ -130,9 +135,9 @@ fn run() -> Result<(), failure::Error> {
         .as_ref()
         .map(|x| x.as_str())
     {
-        None => Some(parse_duration::parse("10ms")?),
+        None => Some(std::time::Duration::from_millis(10)),
         Some("off") => None,
-        Some(d) => Some(parse_duration::parse(&d)?),
+        Some(d) => Some(parse_duration_flag("timestamp-frequency", d)?),
     };
 
     let max_increment_ts_size = popts.opt_get_default("batch-size", 10000_i64)?;
@@ -141,6 +146,7 @@ fn run() -> Result<(), failure::Error> {
     let processes = popts.opt_get_default("processes", 1)?;
     let address_file = popts.opt_str("address-file");
     let gather_metrics = !popts.opt_present("no-prometheus");
+    let read_only = popts.opt_present("read-only");
 
     if process >= processes {
         bail!("process ID {} is not between 0 and {}", process, processes);
@@ -165,6 +171,7 @@ fn run() -> Result<(), failure::Error> {
         data_directory: Some(data_directory),
         symbiosis_url: popts.opt_str("symbiosis"),
         gather_metrics,
+        read_only,
     })?;
 
     // Block forever.
@@ -173,6 +180,15 @@ fn run() -> Result<(), failure::Error> {
     }
 }
 
+/// Parses the value of the duration-valued command-line flag `name`.
+///
+/// Only the strict syntax of [`ore::units::parse_duration`] is accepted, e.g.
+/// `10ms` or `1s`. Looser spellings like `1sec`, `10 ms`, or `1.5s`, which
+/// earlier versions accepted, are rejected.
+fn parse_duration_flag(name: &str, value: &str) -> Result<std::time::Duration, failure::Error> {
+    ore::units::parse_duration(value).map_err(|e| format_err!("parsing --{}: {}", name, e))
+}
+
 fn read_address_file(path: &str, n: usize) -> Result<Vec<SocketAddr>, failure::Error> {
     let file =
         File::open(path).with_context(|err| format!("opening address file {}: {}", path, err))?;
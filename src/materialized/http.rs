@@ -31,6 +31,12 @@ lazy_static! {
         crate::VERSION,
         crate::BUILD_SHA,
     ]);
+    static ref THREAD_CPU_SECONDS: GaugeVec = register_gauge_vec!(
+        "mz_thread_cpu_seconds_total",
+        "CPU time consumed by the threads with a given name",
+        &["thread", "mode"]
+    )
+    .expect("can build mz_thread_cpu_seconds_total");
 }
 
 const METHODS: &[&[u8]] = &[
@@ -219,10 +225,37 @@ fn load_prom_metrics(start_time: Instant) -> Vec<prometheus::proto::MetricFamily
     let uptime = Instant::now() - start_time;
     let (secs, milli_part) = (uptime.as_secs() as f64, uptime.subsec_millis() as f64);
     SERVER_METADATA.set(secs + milli_part / 1_000.0);
+    update_thread_cpu_metrics();
 
     prometheus::gather()
 }
 
+/// Publishes the CPU time of each group of identically-named threads, so that
+/// the saturation of e.g. the timely workers can be observed directly.
+fn update_thread_cpu_metrics() {
+    let times = match ore::metrics::thread_cpu_times() {
+        Ok(times) => times,
+        Err(e) => {
+            log::warn!("unable to sample thread cpu times: {}", e);
+            return;
+        }
+    };
+    let mut totals = BTreeMap::new();
+    for time in times {
+        let total = totals.entry(time.name).or_insert((0.0, 0.0));
+        total.0 += time.user.as_secs_f64();
+        total.1 += time.system.as_secs_f64();
+    }
+    for (name, (user, system)) in totals {
+        THREAD_CPU_SECONDS
+            .with_label_values(&[&name, "user"])
+            .set(user);
+        THREAD_CPU_SECONDS
+            .with_label_values(&[&name, "system"])
+            .set(system);
+    }
+}
+
 #[derive(Debug)]
 enum PromMetric<'a> {
     Counter {
@@ -92,6 +92,10 @@ pub struct Config {
     /// Whether to collect metrics. If enabled, metrics can be collected by
     /// e.g. Prometheus via the `/metrics` HTTP endpoint.
     pub gather_metrics: bool,
+    /// Whether to reject all statements that write data or modify the
+    /// catalog, as for a standby restored from a copy of another server's
+    /// data directory.
+    pub read_only: bool,
 }
 
 impl Config {
@@ -261,6 +265,7 @@ pub fn serve(mut config: Config) -> Result<Server, failure::Error> {
                 None => None,
             },
             executor: &executor,
+            read_only: config.read_only,
         })?;
         Some(thread::spawn(move || coord.serve(cmd_rx)).join_on_drop())
     } else {
@@ -17,6 +17,7 @@ use std::time::Duration;
 
 use futures::stream::{self, StreamExt, TryStreamExt};
 use postgres::error::SqlState;
+use postgres::SimpleQueryMessage;
 use tokio::runtime::Runtime;
 
 pub mod util;
@@ -275,3 +276,119 @@ fn test_persistence() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+#[test]
+fn test_read_only() -> Result<(), Box<dyn Error>> {
+    ore::log::init();
+
+    let data_dir = tempfile::tempdir()?;
+    let config = util::Config::default().data_directory(data_dir.path().to_owned());
+
+    match util::start_server(config.clone().read_only(true)) {
+        Ok(_) => panic!("read-only server unexpectedly booted without a catalog"),
+        Err(e) => assert!(e
+            .to_string()
+            .starts_with("read-only mode requires an existing catalog")),
+    }
+
+    {
+        let (_server, mut client) = util::start_server(config.clone())?;
+        client.batch_execute("CREATE VIEW v AS SELECT 1")?;
+    }
+
+    {
+        let (_server, mut client) = util::start_server(config.read_only(true))?;
+        assert_eq!(
+            client.query_one("SELECT * FROM v", &[])?.get::<_, i32>(0),
+            1
+        );
+        let err = client
+            .batch_execute("CREATE VIEW w AS SELECT 2")
+            .unwrap_err();
+        assert_eq!(err.code(), Some(&SqlState::READ_ONLY_SQL_TRANSACTION));
+        let err = client.batch_execute("DROP VIEW v").unwrap_err();
+        assert_eq!(err.code(), Some(&SqlState::READ_ONLY_SQL_TRANSACTION));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_maintenance_mode() -> Result<(), Box<dyn Error>> {
+    ore::log::init();
+
+    let (server, mut client) = util::start_server(util::Config::default())?;
+    let mut system_client = server
+        .pg_config()
+        .user("mz_system")
+        .connect(postgres::NoTls)?;
+
+    client.batch_execute("CREATE VIEW v AS SELECT 1")?;
+
+    // Only the system user can enter maintenance mode.
+    let err = client
+        .batch_execute("ALTER SYSTEM SET maintenance_mode = 'upgrade in progress'")
+        .unwrap_err();
+    assert_eq!(err.code(), Some(&SqlState::INSUFFICIENT_PRIVILEGE));
+    system_client.batch_execute("ALTER SYSTEM SET maintenance_mode = 'upgrade in progress'")?;
+
+    // Maintenance mode rejects writes from every session, but still serves
+    // reads.
+    for client in &mut [&mut client, &mut system_client] {
+        let err = client
+            .batch_execute("CREATE VIEW w AS SELECT 2")
+            .unwrap_err();
+        assert_eq!(err.code(), Some(&SqlState::OPERATOR_INTERVENTION));
+        let err = client.batch_execute("DROP VIEW v").unwrap_err();
+        assert_eq!(err.code(), Some(&SqlState::OPERATOR_INTERVENTION));
+        assert!(err
+            .to_string()
+            .contains("system is in maintenance mode: upgrade in progress"));
+        client.batch_execute("SELECT 1")?;
+    }
+
+    let err = system_client
+        .batch_execute("ALTER SYSTEM SET maintenance_mode = ''")
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("maintenance_mode must be set to a non-empty message"));
+
+    // Only the system user can leave maintenance mode, too.
+    let err = client
+        .batch_execute("ALTER SYSTEM RESET maintenance_mode")
+        .unwrap_err();
+    assert_eq!(err.code(), Some(&SqlState::INSUFFICIENT_PRIVILEGE));
+    system_client.batch_execute("ALTER SYSTEM RESET maintenance_mode")?;
+    client.batch_execute("DROP VIEW v")?;
+
+    Ok(())
+}
+
+#[test]
+fn test_timezone() -> Result<(), Box<dyn Error>> {
+    ore::log::init();
+
+    let (_server, mut client) = util::start_server(util::Config::default())?;
+
+    // The simple query protocol returns text, which is rendered in the
+    // session's time zone.
+    let render = |client: &mut postgres::Client| -> Result<String, Box<dyn Error>> {
+        let query = "SELECT TIMESTAMP WITH TIME ZONE '2020-03-01 01:02:03+00:00'";
+        match client.simple_query(query)?.into_iter().next() {
+            Some(SimpleQueryMessage::Row(row)) => Ok(row.get(0).unwrap().to_owned()),
+            _ => Err("query returned no rows".into()),
+        }
+    };
+
+    assert_eq!(render(&mut client)?, "2020-03-01 01:02:03+00");
+
+    // Positive offsets are west of Greenwich, as in POSIX and PostgreSQL.
+    client.batch_execute("SET TIME ZONE '+05:30'")?;
+    assert_eq!(render(&mut client)?, "2020-02-29 19:32:03-05:30");
+
+    client.batch_execute("SET TIME ZONE '-08'")?;
+    assert_eq!(render(&mut client)?, "2020-03-01 09:02:03+08");
+
+    Ok(())
+}
@@ -16,6 +16,7 @@ use std::time::Duration;
 pub struct Config {
     data_directory: Option<PathBuf>,
     logging_granularity: Option<Duration>,
+    read_only: bool,
 }
 
 impl Default for Config {
@@ -23,6 +24,7 @@ impl Default for Config {
         Config {
             data_directory: None,
             logging_granularity: Some(Duration::from_millis(10)),
+            read_only: false,
         }
     }
 }
@@ -37,6 +39,11 @@ impl Config {
         self.data_directory = Some(data_directory.into());
         self
     }
+
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
 }
 
 pub fn start_server(config: Config) -> Result<(Server, postgres::Client), Box<dyn Error>> {
@@ -50,6 +57,7 @@ pub fn start_server(config: Config) -> Result<(Server, postgres::Client), Box<dy
         data_directory: config.data_directory,
         symbiosis_url: None,
         gather_metrics: false,
+        read_only: config.read_only,
     })?);
     let client = server.connect()?;
     Ok((server, client))
@@ -18,12 +18,15 @@ use std::marker::PhantomData;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
-use futures::future::{Either, FutureExt, MapOk, TryFuture, TryFutureExt};
+use futures::future::{
+    AbortHandle, Abortable, Aborted, Either, FutureExt, MapOk, TryFuture, TryFutureExt,
+};
 use futures::sink::Sink;
 use futures::stream::{
     Fuse, FuturesUnordered, Stream, StreamExt, StreamFuture, TryStream, TryStreamExt,
 };
 use futures::{io, ready};
+use tokio::task::JoinHandle;
 
 pub mod channel;
 
@@ -171,6 +174,23 @@ pub trait OreStreamExt: Stream {
             active_streams: FuturesUnordered::new(),
         }
     }
+
+    /// Yields items from the stream until `signal` resolves.
+    ///
+    /// Once `signal` resolves, the returned stream terminates, but the
+    /// underlying stream is left untouched. It can be recovered with
+    /// [`TakeUntilSignal::into_inner`] to drain any remaining items, e.g., to
+    /// flush buffered data during shutdown.
+    fn take_until_signal<F>(self, signal: F) -> TakeUntilSignal<Self, F>
+    where
+        Self: Sized,
+        F: Future<Output = ()>,
+    {
+        TakeUntilSignal {
+            stream: self,
+            signal: Some(signal),
+        }
+    }
 }
 
 impl<S: Stream> OreStreamExt for S {}
@@ -270,6 +290,47 @@ where
     }
 }
 
+/// The stream returned by [`OreStreamExt::take_until_signal`].
+#[derive(Debug)]
+pub struct TakeUntilSignal<S, F> {
+    stream: S,
+    /// The shutdown signal, or `None` if it has already resolved.
+    signal: Option<F>,
+}
+
+impl<S, F> TakeUntilSignal<S, F> {
+    /// Reports whether the shutdown signal has resolved.
+    pub fn is_signaled(&self) -> bool {
+        self.signal.is_none()
+    }
+
+    /// Consumes this combinator, returning the underlying stream.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+}
+
+impl<S, F> Stream for TakeUntilSignal<S, F>
+where
+    S: Stream + Unpin,
+    F: Future<Output = ()> + Unpin,
+{
+    type Item = S::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        match &mut self.signal {
+            None => return Poll::Ready(None),
+            Some(signal) => {
+                if let Poll::Ready(()) = signal.poll_unpin(cx) {
+                    self.signal = None;
+                    return Poll::Ready(None);
+                }
+            }
+        }
+        self.stream.poll_next_unpin(cx)
+    }
+}
+
 /// The future returned by [`StreamExt::try_recv`].
 #[derive(Debug)]
 pub struct TryRecv<'a, S>(&'a mut S);
@@ -433,3 +494,223 @@ impl<'a, T: Unpin + Debug> Future for MaybeFuture<'a, T> {
         }
     }
 }
+
+/// A scope that owns a set of concurrently running tasks.
+///
+/// Tasks spawned into the scope with [`TaskScope::spawn`] run on the Tokio
+/// runtime. When the scope is dropped, any of its tasks that have not yet
+/// completed are cancelled, so a scope that is abandoned, e.g. because its
+/// owner errored or was itself cancelled, cannot leak tasks.
+///
+/// To wait for the tasks instead, use [`TaskScope::join`].
+///
+/// ```
+/// use ore::future::TaskScope;
+///
+/// let mut runtime = tokio::runtime::Runtime::new().unwrap();
+/// runtime.block_on(async {
+///     let mut scope = TaskScope::new();
+///     scope.spawn(async { Ok::<_, String>(1) });
+///     scope.spawn(async { Ok(2) });
+///     assert_eq!(scope.join().await, Ok(vec![1, 2]));
+/// });
+/// ```
+pub struct TaskScope<T, E> {
+    tasks: Vec<JoinHandle<Result<Result<T, E>, Aborted>>>,
+    handles: Vec<AbortHandle>,
+}
+
+impl<T, E> TaskScope<T, E>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    /// Constructs a new, empty task scope.
+    pub fn new() -> TaskScope<T, E> {
+        TaskScope {
+            tasks: vec![],
+            handles: vec![],
+        }
+    }
+
+    /// Spawns a task into the scope.
+    ///
+    /// This function must be called from within the context of a Tokio
+    /// runtime.
+    pub fn spawn<F>(&mut self, future: F)
+    where
+        F: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        let (handle, registration) = AbortHandle::new_pair();
+        let task = tokio::spawn(Abortable::new(future, registration));
+        self.tasks.push(task);
+        self.handles.push(handle);
+    }
+
+    /// Returns the number of tasks that have been spawned into the scope.
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Reports whether no tasks have been spawned into the scope.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Waits for every task in the scope to complete.
+    ///
+    /// If every task succeeds, returns their outputs in the order in which
+    /// the tasks were spawned. As soon as any task fails, the remaining tasks
+    /// are cancelled, and the errors from every task that failed before it
+    /// could be cancelled are returned in the order in which they occurred.
+    ///
+    /// Panics if any task panics.
+    ///
+    /// The scope holds on to the tasks' abort handles until the returned
+    /// future completes, so dropping the future early cancels any tasks that
+    /// are still running, just like dropping the scope itself.
+    pub async fn join(mut self) -> Result<Vec<T>, Vec<E>> {
+        let tasks: Vec<_> = self.tasks.drain(..).collect();
+        let mut outputs: Vec<Option<T>> = tasks.iter().map(|_| None).collect();
+        let mut errors = vec![];
+        let mut pending: FuturesUnordered<_> = tasks
+            .into_iter()
+            .enumerate()
+            .map(|(i, task)| task.map(move |res| (i, res)))
+            .collect();
+        while let Some((i, res)) = pending.next().await {
+            match res {
+                Ok(Ok(Ok(output))) => outputs[i] = Some(output),
+                Ok(Ok(Err(err))) => {
+                    if errors.is_empty() {
+                        self.abort();
+                    }
+                    errors.push(err);
+                }
+                // Only tasks that were cancelled after another task failed
+                // are aborted, so there is nothing to record.
+                Ok(Err(Aborted)) => (),
+                Err(err) => panic!("task in scope failed: {}", err),
+            }
+        }
+        if errors.is_empty() {
+            Ok(outputs.into_iter().map(Option::unwrap).collect())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<T, E> Default for TaskScope<T, E>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    fn default() -> TaskScope<T, E> {
+        TaskScope::new()
+    }
+}
+
+impl<T, E> fmt::Debug for TaskScope<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TaskScope")
+            .field("tasks", &self.tasks.len())
+            .finish()
+    }
+}
+
+impl<T, E> TaskScope<T, E> {
+    fn abort(&self) {
+        for handle in &self.handles {
+            handle.abort();
+        }
+    }
+}
+
+impl<T, E> Drop for TaskScope<T, E> {
+    fn drop(&mut self) {
+        self.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::oneshot;
+    use futures::future;
+
+    use super::*;
+
+    #[test]
+    fn test_task_scope_cancels_on_drop() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let (tx, rx) = oneshot::channel::<()>();
+            let mut scope = TaskScope::<(), ()>::new();
+            scope.spawn(async move {
+                let _tx = tx;
+                future::pending().await
+            });
+            drop(scope);
+            // The sender is only dropped if the task is cancelled.
+            assert_eq!(rx.await, Err(oneshot::Canceled));
+        });
+    }
+
+    #[test]
+    fn test_task_scope_cancels_on_join_drop() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let (tx, rx) = oneshot::channel::<()>();
+            let mut scope = TaskScope::<(), ()>::new();
+            scope.spawn(async move {
+                let _tx = tx;
+                future::pending().await
+            });
+            let mut join = Box::pin(scope.join());
+            // Poll the join once, so that it takes ownership of the tasks
+            // before it is dropped.
+            assert!(futures::poll!(join.as_mut()).is_pending());
+            drop(join);
+            assert_eq!(rx.await, Err(oneshot::Canceled));
+        });
+    }
+
+    #[test]
+    fn test_task_scope_join_error() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let mut scope = TaskScope::new();
+            scope.spawn(future::pending());
+            scope.spawn(async { Err("boom") });
+            scope.spawn(async { Ok(1) });
+            assert_eq!(scope.len(), 3);
+            // The pending task must be cancelled, or this would never
+            // complete.
+            assert_eq!(scope.join().await, Err(vec!["boom"]));
+        });
+    }
+
+    #[test]
+    fn test_take_until_signal() {
+        let mut runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async {
+            let (mut item_tx, item_rx) = futures::channel::mpsc::unbounded();
+            let (signal_tx, signal_rx) = oneshot::channel::<()>();
+            let mut stream = item_rx.take_until_signal(signal_rx.map(|_| ()));
+
+            item_tx.unbounded_send(1).unwrap();
+            assert_eq!(stream.next().await, Some(1));
+
+            signal_tx.send(()).unwrap();
+            item_tx.unbounded_send(2).unwrap();
+            assert_eq!(stream.next().await, None);
+            assert!(stream.is_signaled());
+
+            // The remaining items are still available from the inner stream.
+            let mut inner = stream.into_inner();
+            item_tx.close_channel();
+            assert_eq!(inner.next().await, Some(2));
+            assert_eq!(inner.next().await, None);
+        });
+    }
+}
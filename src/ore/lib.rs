@@ -22,6 +22,7 @@ pub mod future;
 pub mod hash;
 pub mod iter;
 pub mod log;
+pub mod metrics;
 pub mod netio;
 pub mod option;
 pub mod panic;
@@ -29,6 +30,7 @@ pub mod stats;
 pub mod sync;
 pub mod thread;
 pub mod tokio;
+pub mod units;
 
 /// Logs a message to stderr and crashes the process.
 ///
@@ -0,0 +1,109 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Process resource usage sampling.
+
+use std::io;
+use std::time::Duration;
+
+/// CPU time consumed by a single thread of the current process.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ThreadCpuTime {
+    /// The name of the thread, as reported by the operating system.
+    ///
+    /// Names are not unique; several threads in a pool usually share one.
+    pub name: String,
+    /// Time spent executing in user mode.
+    pub user: Duration,
+    /// Time spent executing in kernel mode.
+    pub system: Duration,
+}
+
+/// Samples the CPU time consumed by each thread of the current process.
+///
+/// Only Linux is supported, where the times are read from `/proc/self/task`.
+/// On other platforms an empty list is returned. Threads that exit while the
+/// sample is being taken are silently skipped.
+pub fn thread_cpu_times() -> Result<Vec<ThreadCpuTime>, io::Error> {
+    if !cfg!(target_os = "linux") {
+        return Ok(vec![]);
+    }
+    let ticks_per_sec = match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+        n if n > 0 => n as u64,
+        _ => return Err(io::Error::last_os_error()),
+    };
+    let mut out = vec![];
+    for entry in std::fs::read_dir("/proc/self/task")? {
+        let stat = match std::fs::read_to_string(entry?.path().join("stat")) {
+            Ok(stat) => stat,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        if let Some(time) = parse_stat(&stat, ticks_per_sec) {
+            out.push(time);
+        }
+    }
+    Ok(out)
+}
+
+/// Parses the contents of a `/proc/[pid]/task/[tid]/stat` file.
+///
+/// The thread name is enclosed in parentheses and may itself contain spaces
+/// and parentheses, so the fixed-position fields are located relative to the
+/// last closing parenthesis.
+fn parse_stat(stat: &str, ticks_per_sec: u64) -> Option<ThreadCpuTime> {
+    let open = stat.find('(')?;
+    let close = stat.rfind(')')?;
+    let name = stat.get(open + 1..close)?.to_owned();
+    // The first field after the name is the thread state (field 3), so user
+    // and system time (fields 14 and 15) are at offsets 11 and 12.
+    let mut fields = stat[close + 1..].split_whitespace().skip(11);
+    let user: u64 = fields.next()?.parse().ok()?;
+    let system: u64 = fields.next()?.parse().ok()?;
+    let to_duration = |ticks: u64| {
+        Duration::from_secs(ticks / ticks_per_sec)
+            + Duration::from_nanos((ticks % ticks_per_sec) * 1_000_000_000 / ticks_per_sec)
+    };
+    Some(ThreadCpuTime {
+        name,
+        user: to_duration(user),
+        system: to_duration(system),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stat() {
+        let stat = "4242 (timely:work-0) S 1 4242 4242 0 -1 4194368 2047 0 0 0 \
+                    250 75 0 0 20 0 12 0 1234 1000000 500 18446744073709551615";
+        assert_eq!(
+            parse_stat(stat, 100),
+            Some(ThreadCpuTime {
+                name: "timely:work-0".into(),
+                user: Duration::from_millis(2500),
+                system: Duration::from_millis(750),
+            })
+        );
+
+        let stat = "7 (a (weird) name) R 1 7 7 0 -1 0 0 0 0 0 3 1 0 0 20 0";
+        assert_eq!(
+            parse_stat(stat, 100).map(|t| (t.name, t.user, t.system)),
+            Some((
+                "a (weird) name".into(),
+                Duration::from_millis(30),
+                Duration::from_millis(10)
+            ))
+        );
+
+        assert_eq!(parse_stat("garbage", 100), None);
+    }
+}
@@ -9,8 +9,13 @@
 
 //! Panic utilities.
 
-use std::panic;
+use std::cell::{Cell, RefCell};
+use std::error::Error;
+use std::fmt;
+use std::panic::{self, UnwindSafe};
 use std::process;
+use std::sync::Once;
+use std::thread;
 
 /// Instructs the entire process to abort if any thread panics.
 ///
@@ -27,8 +32,8 @@ use std::process;
 /// forever will be more confusing to the end user than aborting the program
 /// entirely.
 ///
-/// Computations in which a panic is expected can still use
-/// [`panic::catch_unwind`] to recover.
+/// Computations in which a panic is expected can still use [`catch_panic`] to
+/// recover.
 pub fn set_abort_on_panic() {
     let old_hook = panic::take_hook();
     panic::set_hook(Box::new(move |panic_info| {
@@ -36,3 +41,128 @@ pub fn set_abort_on_panic() {
         process::abort();
     }))
 }
+
+thread_local! {
+    static CAPTURE_DEPTH: Cell<usize> = Cell::new(0);
+    static CAPTURED: RefCell<Option<CaughtPanic>> = RefCell::new(None);
+}
+
+static INSTALL_CAPTURE_HOOK: Once = Once::new();
+
+/// A panic that was captured by [`catch_panic`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaughtPanic {
+    /// The panic message, or `Box<Any>` if the panic payload was not a
+    /// string.
+    pub message: String,
+    /// The source location at which the panic occurred, if known.
+    pub location: Option<String>,
+    /// The name of the thread that panicked, if it had one.
+    pub thread: Option<String>,
+}
+
+impl fmt::Display for CaughtPanic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "panicked at '{}'", self.message)?;
+        if let Some(location) = &self.location {
+            write!(f, ", {}", location)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for CaughtPanic {}
+
+/// Installs a panic hook that captures panics raised inside [`catch_panic`].
+///
+/// Panics raised outside of `catch_panic` are passed through to whatever hook
+/// was installed previously, so this function should be called after any
+/// process-wide hook, like the one installed by [`set_abort_on_panic`], is in
+/// place. Calling this function more than once has no additional effect.
+pub fn install_capture_hook() {
+    INSTALL_CAPTURE_HOOK.call_once(|| {
+        let old_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |panic_info| {
+            if CAPTURE_DEPTH.with(|depth| depth.get()) == 0 {
+                return old_hook(panic_info);
+            }
+            let payload = panic_info.payload();
+            let message = match payload.downcast_ref::<&'static str>() {
+                Some(s) => (*s).to_owned(),
+                None => match payload.downcast_ref::<String>() {
+                    Some(s) => s.clone(),
+                    None => "Box<Any>".to_owned(),
+                },
+            };
+            let caught = CaughtPanic {
+                message,
+                location: panic_info.location().map(|l| l.to_string()),
+                thread: thread::current().name().map(|n| n.to_owned()),
+            };
+            CAPTURED.with(|captured| *captured.borrow_mut() = Some(caught));
+        }))
+    })
+}
+
+/// Runs `f`, converting a panic into a [`CaughtPanic`] error instead of
+/// unwinding into the caller.
+///
+/// The hook installed by [`install_capture_hook`] is responsible for
+/// recording the details of the panic and for keeping the process-wide hook
+/// from reporting it. This function installs that hook if necessary.
+///
+/// ```
+/// use ore::panic::catch_panic;
+///
+/// assert_eq!(catch_panic(|| 42), Ok(42));
+/// let err = catch_panic(|| -> i32 { panic!("oh no") }).unwrap_err();
+/// assert_eq!(err.message, "oh no");
+/// ```
+pub fn catch_panic<F, R>(f: F) -> Result<R, CaughtPanic>
+where
+    F: FnOnce() -> R + UnwindSafe,
+{
+    install_capture_hook();
+    CAPTURE_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let res = panic::catch_unwind(f);
+    CAPTURE_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    res.map_err(|_| {
+        CAPTURED
+            .with(|captured| captured.borrow_mut().take())
+            .expect("capture hook did not record panic")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch_panic() {
+        let err = catch_panic(|| -> i32 { panic!("oh no") }).unwrap_err();
+        assert_eq!(err.message, "oh no");
+        assert!(err.location.unwrap().contains("panic.rs"));
+
+        // Panics raised by the standard library are reported at a location in
+        // the standard library, so only check the message.
+        let err = catch_panic(|| {
+            let v: Vec<i32> = vec![];
+            v[0]
+        })
+        .unwrap_err();
+        assert!(err.message.contains("index out of bounds"));
+
+        let err = catch_panic(|| panic!("formatted {}", 42)).unwrap_err();
+        assert_eq!(err.message, "formatted 42");
+    }
+
+    #[test]
+    fn test_catch_panic_nested() {
+        let res = catch_panic(|| {
+            let inner = catch_panic(|| panic!("inner"));
+            assert_eq!(inner.unwrap_err().message, "inner");
+            "outer"
+        });
+        assert_eq!(res, Ok("outer"));
+    }
+}
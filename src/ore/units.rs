@@ -0,0 +1,338 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Parsing and formatting of byte sizes and durations.
+//!
+//! The parsers in this module are deliberately strict. A value is an unsigned
+//! integer immediately followed by exactly one unit suffix, with no
+//! intervening whitespace, fractional part, or sign. Suffixes are case
+//! sensitive, so that, e.g., `m` (minutes) and `M` are never confused. Every
+//! configuration surface that accepts a byte size or duration should use these
+//! parsers, so that users need only learn one syntax.
+//!
+//! The formatters produce the shortest representation that the corresponding
+//! parser accepts, so that formatting and then parsing a value is lossless.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+type Units = &'static [(&'static str, u64)];
+
+const BYTE_UNITS: Units = &[
+    ("B", 1),
+    ("kB", 1_000),
+    ("MB", 1_000_000),
+    ("GB", 1_000_000_000),
+    ("TB", 1_000_000_000_000),
+    ("KiB", 1 << 10),
+    ("MiB", 1 << 20),
+    ("GiB", 1 << 30),
+    ("TiB", 1 << 40),
+];
+
+const BINARY_BYTE_UNITS: Units = &[
+    ("B", 1),
+    ("KiB", 1 << 10),
+    ("MiB", 1 << 20),
+    ("GiB", 1 << 30),
+    ("TiB", 1 << 40),
+];
+
+const DURATION_UNITS: Units = &[
+    ("ns", 1),
+    ("us", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+    ("m", 60 * 1_000_000_000),
+    ("h", 60 * 60 * 1_000_000_000),
+    ("d", 24 * 60 * 60 * 1_000_000_000),
+];
+
+/// A size in bytes.
+///
+/// Byte sizes are written as an integer followed by one of the decimal units
+/// `B`, `kB`, `MB`, `GB`, or `TB`, or one of the binary units `KiB`, `MiB`,
+/// `GiB`, or `TiB`.
+///
+/// ```
+/// use ore::units::ByteSize;
+///
+/// let size: ByteSize = "512MiB".parse().unwrap();
+/// assert_eq!(size.as_bytes(), 512 * 1024 * 1024);
+/// assert_eq!(size.to_string(), "512MiB");
+/// assert!("512 MiB".parse::<ByteSize>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// Constructs a byte size from a number of bytes.
+    pub fn from_bytes(bytes: u64) -> ByteSize {
+        ByteSize(bytes)
+    }
+
+    /// Returns the number of bytes in this byte size.
+    pub fn as_bytes(&self) -> u64 {
+        self.0
+    }
+}
+
+impl FromStr for ByteSize {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<ByteSize, ParseError> {
+        parse_with_units(s, BYTE_UNITS, "byte size").map(ByteSize)
+    }
+}
+
+impl fmt::Display for ByteSize {
+    /// Formats the byte size using the largest binary unit that represents it
+    /// exactly.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        format_with_units(f, self.0, BINARY_BYTE_UNITS)
+    }
+}
+
+/// Parses a duration.
+///
+/// Durations are written as an integer followed by one of the units `ns`,
+/// `us`, `ms`, `s`, `m`, `h`, or `d`.
+///
+/// ```
+/// use std::time::Duration;
+/// use ore::units::parse_duration;
+///
+/// assert_eq!(parse_duration("30s").unwrap(), Duration::from_secs(30));
+/// assert_eq!(parse_duration("5m").unwrap(), Duration::from_secs(300));
+/// assert!(parse_duration("5").is_err());
+/// ```
+pub fn parse_duration(s: &str) -> Result<Duration, ParseError> {
+    parse_with_units(s, DURATION_UNITS, "duration").map(Duration::from_nanos)
+}
+
+/// Formats a duration in the syntax accepted by [`parse_duration`], using the
+/// largest unit that represents it exactly.
+///
+/// ```
+/// use std::time::Duration;
+/// use ore::units::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_secs(300)), "5m");
+/// assert_eq!(format_duration(Duration::from_millis(1500)), "1500ms");
+/// ```
+///
+/// Panics if the duration does not fit in 64 bits of nanoseconds.
+pub fn format_duration(d: Duration) -> String {
+    struct Formatted(u64);
+
+    impl fmt::Display for Formatted {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            format_with_units(f, self.0, DURATION_UNITS)
+        }
+    }
+
+    let nanos = d.as_nanos();
+    assert!(
+        nanos <= u128::from(u64::max_value()),
+        "duration too large to format"
+    );
+    Formatted(nanos as u64).to_string()
+}
+
+fn parse_with_units(s: &str, units: Units, what: &'static str) -> Result<u64, ParseError> {
+    let err = |reason| ParseError {
+        what,
+        input: s.to_owned(),
+        reason,
+    };
+    let split = s
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or_else(|| s.len());
+    let (number, suffix) = s.split_at(split);
+    if number.is_empty() {
+        return Err(err(ParseErrorReason::MissingNumber));
+    }
+    let number: u64 = number
+        .parse()
+        .map_err(|_| err(ParseErrorReason::Overflow))?;
+    if suffix.is_empty() {
+        return Err(err(ParseErrorReason::MissingUnit(units)));
+    }
+    let multiplier = match units.iter().find(|(name, _)| *name == suffix) {
+        Some((_, multiplier)) => multiplier,
+        None => return Err(err(ParseErrorReason::UnknownUnit(units))),
+    };
+    number
+        .checked_mul(*multiplier)
+        .ok_or_else(|| err(ParseErrorReason::Overflow))
+}
+
+/// Writes `n` using the largest of `units` that divides it exactly. The units
+/// must be sorted in increasing order, and the first unit must have a
+/// multiplier of one.
+fn format_with_units(f: &mut fmt::Formatter, n: u64, units: Units) -> fmt::Result {
+    let (name, multiplier) = units
+        .iter()
+        .rev()
+        .find(|(_, multiplier)| n != 0 && n % multiplier == 0)
+        .unwrap_or(&units[0]);
+    write!(f, "{}{}", n / multiplier, name)
+}
+
+/// An error returned when parsing a byte size or duration fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    what: &'static str,
+    input: String,
+    reason: ParseErrorReason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ParseErrorReason {
+    MissingNumber,
+    MissingUnit(Units),
+    UnknownUnit(Units),
+    Overflow,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid {} '{}': ", self.what, self.input)?;
+        match &self.reason {
+            ParseErrorReason::MissingNumber => f.write_str("must begin with an unsigned integer"),
+            ParseErrorReason::MissingUnit(units) | ParseErrorReason::UnknownUnit(units) => {
+                f.write_str("unit must be one of ")?;
+                for (i, (name, _)) in units.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    f.write_str(name)?;
+                }
+                Ok(())
+            }
+            ParseErrorReason::Overflow => f.write_str("value out of range"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_byte_size() {
+        for (input, bytes) in &[
+            ("0B", 0),
+            ("100B", 100),
+            ("2kB", 2_000),
+            ("3MB", 3_000_000),
+            ("1KiB", 1024),
+            ("512MiB", 512 << 20),
+            ("4GiB", 4 << 30),
+            ("1TiB", 1 << 40),
+        ] {
+            assert_eq!(input.parse::<ByteSize>(), Ok(ByteSize(*bytes)), "{}", input);
+        }
+        for input in &[
+            "",
+            "B",
+            "100",
+            "100 MiB",
+            "1.5GiB",
+            "-1B",
+            "1mib",
+            "1KB",
+            "1kb",
+            "1PiB",
+            "99999999999TiB",
+        ] {
+            assert!(input.parse::<ByteSize>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_byte_size_display() {
+        for (bytes, output) in &[
+            (0, "0B"),
+            (100, "100B"),
+            (2_000, "2000B"),
+            (1024, "1KiB"),
+            (1536, "1536B"),
+            (512 << 20, "512MiB"),
+            (3 << 30, "3GiB"),
+            (5 << 40, "5TiB"),
+        ] {
+            let size = ByteSize(*bytes);
+            assert_eq!(size.to_string(), *output);
+            assert_eq!(output.parse::<ByteSize>(), Ok(size));
+        }
+    }
+
+    #[test]
+    fn test_duration() {
+        for (input, duration) in &[
+            ("0s", Duration::from_secs(0)),
+            ("10ns", Duration::from_nanos(10)),
+            ("10us", Duration::from_micros(10)),
+            ("10ms", Duration::from_millis(10)),
+            ("30s", Duration::from_secs(30)),
+            ("5m", Duration::from_secs(300)),
+            ("2h", Duration::from_secs(7200)),
+            ("1d", Duration::from_secs(86400)),
+        ] {
+            assert_eq!(parse_duration(input), Ok(*duration), "{}", input);
+        }
+        for input in &[
+            "",
+            "s",
+            "10",
+            "10 s",
+            "1.5s",
+            "-1s",
+            "1S",
+            "1sec",
+            "1h30m",
+            "1000000000d",
+        ] {
+            assert!(parse_duration(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_duration_format() {
+        for (duration, output) in &[
+            (Duration::from_secs(0), "0ns"),
+            (Duration::from_nanos(1), "1ns"),
+            (Duration::from_millis(10), "10ms"),
+            (Duration::from_millis(1500), "1500ms"),
+            (Duration::from_secs(90), "90s"),
+            (Duration::from_secs(300), "5m"),
+            (Duration::from_secs(86400), "1d"),
+        ] {
+            assert_eq!(format_duration(*duration), *output);
+            assert_eq!(parse_duration(output), Ok(*duration));
+        }
+    }
+
+    #[test]
+    fn test_error_message() {
+        assert_eq!(
+            parse_duration("5").unwrap_err().to_string(),
+            "invalid duration '5': unit must be one of ns, us, ms, s, m, h, d"
+        );
+        assert_eq!(
+            "MiB".parse::<ByteSize>().unwrap_err().to_string(),
+            "invalid byte size 'MiB': must begin with an unsigned integer"
+        );
+    }
+}
@@ -11,7 +11,7 @@ use std::error::Error;
 use std::str;
 
 use bytes::{BufMut, BytesMut};
-use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use postgres_types::{FromSql, IsNull, ToSql, Type as PgType};
 
 use repr::decimal::MAX_DECIMAL_PRECISION;
@@ -119,23 +119,27 @@ impl Value {
     }
 
     /// Serializes this value to `buf` in the specified `format`.
-    pub fn encode(&self, format: Format, buf: &mut BytesMut) {
+    ///
+    /// Timestamps with time zones are rendered in `timezone` if `format` is
+    /// [`Format::Text`].
+    pub fn encode(&self, format: Format, timezone: FixedOffset, buf: &mut BytesMut) {
         match format {
-            Format::Text => self.encode_text(buf),
+            Format::Text => self.encode_text(timezone, buf),
             Format::Binary => self.encode_binary(buf),
         }
     }
 
     /// Serializes this value to `buf` using the [text encoding
-    /// format](Format::Text).
-    pub fn encode_text(&self, buf: &mut BytesMut) {
+    /// format](Format::Text), rendering timestamps with time zones in
+    /// `timezone`.
+    pub fn encode_text(&self, timezone: FixedOffset, buf: &mut BytesMut) {
         match self {
             Value::Bool(b) => strconv::format_bool(buf, *b),
             Value::Bytea(b) => strconv::format_bytes(buf, b),
             Value::Date(d) => strconv::format_date(buf, *d),
             Value::Time(t) => strconv::format_time(buf, *t),
             Value::Timestamp(ts) => strconv::format_timestamp(buf, *ts),
-            Value::TimestampTz(ts) => strconv::format_timestamptz(buf, *ts),
+            Value::TimestampTz(ts) => strconv::format_timestamptz_in(buf, *ts, timezone),
             Value::Interval(iv) => strconv::format_interval(buf, iv.0),
             Value::Int4(i) => strconv::format_int32(buf, *i),
             Value::Int8(i) => strconv::format_int64(buf, *i),
@@ -130,7 +130,7 @@ impl Encoder for Codec {
             BackendMessage::EncryptionResponse(_) => unreachable!(),
             BackendMessage::AuthenticationOk => b'R',
             BackendMessage::RowDescription(_) => b'T',
-            BackendMessage::DataRow(_, _) => b'D',
+            BackendMessage::DataRow(_, _, _) => b'D',
             BackendMessage::CommandComplete { .. } => b'C',
             BackendMessage::EmptyQueryResponse => b'I',
             BackendMessage::ReadyForQuery(_) => b'Z',
@@ -187,13 +187,13 @@ impl Encoder for Codec {
                     dst.put_u16(f.format as u16);
                 }
             }
-            BackendMessage::DataRow(fields, formats) => {
+            BackendMessage::DataRow(fields, formats, timezone) => {
                 dst.put_u16(fields.len() as u16);
                 for (f, ff) in fields.iter().zip(formats.iter()) {
                     if let Some(f) = f {
                         let base = dst.len();
                         dst.put_u32(0);
-                        f.encode(*ff, dst);
+                        f.encode(*ff, timezone, dst);
                         let len = dst.len() - base - 4;
                         let len = (len as u32).to_be_bytes();
                         dst[base..base + 4].copy_from_slice(&len);
@@ -10,6 +10,7 @@
 use std::sync::Arc;
 
 use bytes::BytesMut;
+use chrono::FixedOffset;
 
 use dataflow_types::Update;
 use repr::{ColumnName, RelationDesc, RelationType, ScalarType};
@@ -251,7 +252,12 @@ pub enum BackendMessage {
     EncryptionResponse(EncryptionType),
     ReadyForQuery(TransactionStatus),
     RowDescription(Vec<FieldDescription>),
-    DataRow(Vec<Option<pgrepr::Value>>, Arc<Vec<pgrepr::Format>>),
+    /// The values of a row, their formats, and the session's time zone.
+    DataRow(
+        Vec<Option<pgrepr::Value>>,
+        Arc<Vec<pgrepr::Format>>,
+        FixedOffset,
+    ),
     ParameterStatus(&'static str, String),
     BackendKeyData {
         conn_id: u32,
@@ -325,7 +331,7 @@ pub struct FieldDescription {
     pub format: pgrepr::Format,
 }
 
-pub fn encode_update(update: Update, typ: &RelationType) -> Vec<u8> {
+pub fn encode_update(update: Update, typ: &RelationType, timezone: FixedOffset) -> Vec<u8> {
     let mut out = Vec::new();
     let mut buf = BytesMut::new();
     for field in pgrepr::values_from_row(update.row, typ) {
@@ -333,7 +339,7 @@ pub fn encode_update(update: Update, typ: &RelationType) -> Vec<u8> {
             None => out.extend(b"\\N"),
             Some(field) => {
                 buf.clear();
-                field.encode_text(&mut buf);
+                field.encode_text(timezone, &mut buf);
                 for b in &buf {
                     match b {
                         b'\\' => out.extend(b"\\\\"),
@@ -256,7 +256,7 @@ where
         }
 
         for (name, value) in params {
-            let _ = session.set(&name, &value);
+            let _ = session.set_startup_param(&name, &value);
         }
 
         let (tx, rx) = futures::channel::oneshot::channel();
@@ -414,7 +414,7 @@ where
                 coord::Response {
                     result: Err(err),
                     session,
-                } => self.error(session, "99999", err.to_string()).await,
+                } => self.error(session, error_code(&err), err.to_string()).await,
             }
         };
         match run.await? {
@@ -559,7 +559,7 @@ where
             coord::Response {
                 result: Err(err),
                 session,
-            } => self.error(session, "99999", err.to_string()).await,
+            } => self.error(session, error_code(&err), err.to_string()).await,
         }
     }
 
@@ -693,7 +693,7 @@ where
             ExecuteResponse::CreatedIndex { existed } => created!(existed, "42710", "index"),
             ExecuteResponse::CreatedSource { existed } => created!(existed, "42710", "source"),
             ExecuteResponse::CreatedSink { existed } => created!(existed, "42710", "sink"),
-            ExecuteResponse::CreatedView => command_complete!("CREATE VIEW"),
+            ExecuteResponse::CreatedView { existed } => created!(existed, "42P07", "view"),
             ExecuteResponse::Deleted(n) => command_complete!("DELETE {}", n),
             ExecuteResponse::DroppedDatabase => command_complete!("DROP DATABASE"),
             ExecuteResponse::DroppedSchema => command_complete!("DROP SCHEMA"),
@@ -735,7 +735,11 @@ where
                 // This code is somewhat awkwardly structured because we
                 // can't hold `var` across an await point.
                 let qn = name.to_string();
-                let msg = if let Some(var) = session.notify_vars().iter().find(|v| v.name() == qn) {
+                let msg = if let Some(var) = session
+                    .notify_vars()
+                    .iter()
+                    .find(|v| v.name().eq_ignore_ascii_case(&qn))
+                {
                     Some(BackendMessage::ParameterStatus(var.name(), var.value()))
                 } else {
                     None
@@ -748,6 +752,8 @@ where
             ExecuteResponse::StartTransaction => command_complete!("BEGIN"),
             ExecuteResponse::Commit => command_complete!("COMMIT TRANSACTION"),
             ExecuteResponse::Rollback => command_complete!("ROLLBACK TRANSACTION"),
+            ExecuteResponse::Discarded(target) => command_complete!("DISCARD {}", target),
+            ExecuteResponse::AlteredSystemConfiguration => command_complete!("ALTER SYSTEM"),
             ExecuteResponse::Tailing { rx } => {
                 let row_desc =
                     row_desc.expect("missing row description for ExecuteResponse::Tailing");
@@ -765,6 +771,7 @@ where
         mut rows: Vec<Row>,
         max_rows: i32,
     ) -> Result<State, comm::Error> {
+        let timezone = session.timezone();
         let portal = session
             .get_portal_mut(&portal_name)
             .expect("valid portal name for send rows");
@@ -780,6 +787,7 @@ where
                 BackendMessage::DataRow(
                     pgrepr::values_from_row(row, row_desc.typ()),
                     formats.clone(),
+                    timezone,
                 )
             }),
         )
@@ -805,6 +813,7 @@ where
         mut rx: comm::mpsc::Receiver<Vec<Update>>,
     ) -> Result<State, comm::Error> {
         let typ = row_desc.typ();
+        let timezone = session.timezone();
         let column_formats = iter::repeat(pgrepr::Format::Text)
             .take(typ.column_types.len())
             .collect();
@@ -823,7 +832,7 @@ where
                     count += updates.len();
                     for update in updates {
                         self.send(BackendMessage::CopyData(message::encode_update(
-                            update, typ,
+                            update, typ, timezone,
                         )))
                         .await?;
                     }
@@ -941,3 +950,17 @@ fn pad_formats(formats: Vec<pgrepr::Format>, n: usize) -> Result<Vec<pgrepr::For
         )),
     }
 }
+
+/// Returns the SQLSTATE code to report for an error returned by the
+/// coordinator.
+fn error_code(err: &failure::Error) -> &'static str {
+    if err.downcast_ref::<coord::ReadOnlyError>().is_some() {
+        "25006" // read_only_sql_transaction
+    } else if err.downcast_ref::<coord::MaintenanceModeError>().is_some() {
+        "57000" // operator_intervention
+    } else if err.downcast_ref::<coord::PrivilegeError>().is_some() {
+        "42501" // insufficient_privilege
+    } else {
+        "99999"
+    }
+}
@@ -26,7 +26,9 @@ mod relation;
 mod row;
 mod scalar;
 
-pub use relation::{ColumnName, ColumnType, RelationDesc, RelationType};
+pub use relation::{
+    ColumnName, ColumnType, CompatibilityMode, Incompatibility, RelationDesc, RelationType,
+};
 pub use row::{datum_size, DatumDict, DatumList, Row, RowArena, RowPacker};
 pub use scalar::{decimal, jsonb, regex, strconv};
 pub use scalar::{Datum, Interval, ScalarType};
@@ -229,4 +229,221 @@ impl RelationDesc {
     pub fn set_name(&mut self, i: usize, name: Option<ColumnName>) {
         self.names[i] = name
     }
+
+    /// Reports whether this description can stand in for `other`, an earlier
+    /// version of the same relation, under the rules of `mode`.
+    ///
+    /// Columns are matched by name; unnamed columns are matched by position.
+    /// Adding or removing a column is permitted only if readers on the other
+    /// side can fill it with nulls, and a column's nullability may only be
+    /// widened in the direction `mode` allows. Changing a column's scalar type
+    /// is never compatible. On failure, every incompatibility is returned,
+    /// not just the first.
+    pub fn is_compatible_with(
+        &self,
+        other: &RelationDesc,
+        mode: CompatibilityMode,
+    ) -> Result<(), Vec<Incompatibility>> {
+        let backward = mode != CompatibilityMode::Forward;
+        let forward = mode != CompatibilityMode::Backward;
+        let mut errors = vec![];
+
+        for (i, (name, new)) in self.iter().enumerate() {
+            let column = column_label(i, name);
+            match other.find_column(i, name) {
+                None => {
+                    if backward && !new.nullable {
+                        errors.push(Incompatibility::AddedNotNullable { column });
+                    }
+                }
+                Some(old) => {
+                    if old.scalar_type != new.scalar_type {
+                        errors.push(Incompatibility::TypeChanged {
+                            column,
+                            old: old.scalar_type.clone(),
+                            new: new.scalar_type.clone(),
+                        });
+                    } else if backward && old.nullable && !new.nullable {
+                        errors.push(Incompatibility::NullabilityNarrowed { column });
+                    } else if forward && !old.nullable && new.nullable {
+                        errors.push(Incompatibility::NullabilityWidened { column });
+                    }
+                }
+            }
+        }
+
+        for (i, (name, old)) in other.iter().enumerate() {
+            if self.find_column(i, name).is_none() && forward && !old.nullable {
+                errors.push(Incompatibility::RemovedNotNullable {
+                    column: column_label(i, name),
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Finds the column that corresponds to the column at position `i` with
+    /// name `name` in another description of this relation.
+    fn find_column(&self, i: usize, name: Option<&ColumnName>) -> Option<&ColumnType> {
+        match name {
+            Some(name) => self.get_by_name(name).map(|(_i, typ)| typ),
+            None => match self.names.get(i) {
+                Some(None) => Some(&self.typ.column_types[i]),
+                _ => None,
+            },
+        }
+    }
+}
+
+fn column_label(i: usize, name: Option<&ColumnName>) -> String {
+    match name {
+        Some(name) => name.to_string(),
+        None => format!("#{}", i),
+    }
+}
+
+/// The direction in which [`RelationDesc::is_compatible_with`] checks that a
+/// new description of a relation is compatible with an old one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CompatibilityMode {
+    /// Consumers of the new description can read data produced under the old
+    /// description.
+    Backward,
+    /// Consumers of the old description can read data produced under the new
+    /// description.
+    Forward,
+    /// Both `Backward` and `Forward`.
+    Full,
+}
+
+/// A reason that two descriptions of a relation are not compatible.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Incompatibility {
+    /// A non-nullable column was added.
+    AddedNotNullable { column: String },
+    /// A non-nullable column was removed.
+    RemovedNotNullable { column: String },
+    /// A column's scalar type changed.
+    TypeChanged {
+        column: String,
+        old: ScalarType,
+        new: ScalarType,
+    },
+    /// A nullable column became non-nullable.
+    NullabilityNarrowed { column: String },
+    /// A non-nullable column became nullable.
+    NullabilityWidened { column: String },
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Incompatibility::AddedNotNullable { column } => {
+                write!(f, "added column {} is not nullable", column)
+            }
+            Incompatibility::RemovedNotNullable { column } => {
+                write!(f, "removed column {} was not nullable", column)
+            }
+            Incompatibility::TypeChanged { column, old, new } => {
+                write!(f, "column {} changed type from {} to {}", column, old, new)
+            }
+            Incompatibility::NullabilityNarrowed { column } => {
+                write!(f, "column {} is no longer nullable", column)
+            }
+            Incompatibility::NullabilityWidened { column } => {
+                write!(f, "column {} became nullable", column)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_compatible_with() {
+        use CompatibilityMode::*;
+
+        let old = RelationDesc::empty()
+            .add_column("a", ScalarType::Int64)
+            .add_column("b", ScalarType::String);
+
+        // Identical descriptions are compatible in every mode.
+        for mode in &[Backward, Forward, Full] {
+            assert_eq!(old.is_compatible_with(&old, *mode), Ok(()));
+        }
+
+        // Adding a nullable column, or reordering, is always fine.
+        let new = RelationDesc::new(
+            RelationType::new(vec![
+                ColumnType::new(ScalarType::String),
+                ColumnType::new(ScalarType::Bool).nullable(true),
+                ColumnType::new(ScalarType::Int64),
+            ]),
+            vec![Some("b"), Some("c"), Some("a")],
+        );
+        assert_eq!(new.is_compatible_with(&old, Full), Ok(()));
+
+        // Adding a non-nullable column breaks backward compatibility only.
+        let new = old.clone().add_column("c", ScalarType::Bool);
+        assert_eq!(new.is_compatible_with(&old, Forward), Ok(()));
+        assert_eq!(
+            new.is_compatible_with(&old, Backward),
+            Err(vec![Incompatibility::AddedNotNullable {
+                column: "c".into()
+            }])
+        );
+
+        // Removing a non-nullable column breaks forward compatibility only.
+        let new = RelationDesc::empty().add_column("a", ScalarType::Int64);
+        assert_eq!(new.is_compatible_with(&old, Backward), Ok(()));
+        assert_eq!(
+            new.is_compatible_with(&old, Forward),
+            Err(vec![Incompatibility::RemovedNotNullable {
+                column: "b".into()
+            }])
+        );
+
+        // Widening nullability breaks forward compatibility only.
+        let new = RelationDesc::new(
+            RelationType::new(vec![
+                ColumnType::new(ScalarType::Int64).nullable(true),
+                ColumnType::new(ScalarType::String),
+            ]),
+            vec![Some("a"), Some("b")],
+        );
+        assert_eq!(new.is_compatible_with(&old, Backward), Ok(()));
+        assert_eq!(
+            new.is_compatible_with(&old, Forward),
+            Err(vec![Incompatibility::NullabilityWidened {
+                column: "a".into()
+            }])
+        );
+        assert_eq!(
+            old.is_compatible_with(&new, Backward),
+            Err(vec![Incompatibility::NullabilityNarrowed {
+                column: "a".into()
+            }])
+        );
+
+        // Type changes are never compatible, and all errors are reported.
+        let new = RelationDesc::empty()
+            .add_column("a", ScalarType::String)
+            .add_column("c", ScalarType::Bool);
+        let errors = new.is_compatible_with(&old, Full).unwrap_err();
+        assert_eq!(
+            errors.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            vec![
+                "column a changed type from i64 to string",
+                "added column c is not nullable",
+                "removed column b was not nullable",
+            ]
+        );
+    }
 }
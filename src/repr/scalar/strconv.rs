@@ -23,7 +23,7 @@
 //! string representations for the corresponding PostgreSQL type. Deviations
 //! should be considered a bug.
 
-use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Utc};
 use failure::bail;
 
 use ore::fmt::FormatBuffer;
@@ -163,8 +163,26 @@ pub fn format_timestamptz<F>(buf: &mut F, ts: DateTime<Utc>)
 where
     F: FormatBuffer,
 {
-    write!(buf, "{}", ts.format("%Y-%m-%d %H:%M:%S+00"));
+    format_timestamptz_in(buf, ts, FixedOffset::east(0))
+}
+
+/// Like [`format_timestamptz`], but renders `ts` as a local time in the time
+/// zone described by `offset`, as PostgreSQL does for the session's
+/// `TimeZone`.
+pub fn format_timestamptz_in<F>(buf: &mut F, ts: DateTime<Utc>, offset: FixedOffset)
+where
+    F: FormatBuffer,
+{
+    let ts = ts.with_timezone(&offset);
+    write!(buf, "{}", ts.format("%Y-%m-%d %H:%M:%S"));
     format_nanos(buf, ts.timestamp_subsec_nanos());
+    let secs = offset.local_minus_utc();
+    let sign = if secs < 0 { '-' } else { '+' };
+    let (hours, minutes) = (secs.abs() / 3600, secs.abs() % 3600 / 60);
+    write!(buf, "{}{:02}", sign, hours);
+    if minutes != 0 {
+        write!(buf, ":{:02}", minutes);
+    }
 }
 
 pub fn parse_interval(_s: &str) -> Result<Interval, failure::Error> {
@@ -265,3 +283,29 @@ where
         write!(buf, ".{:0width$}", nanos, width = width);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_format_timestamptz_in() {
+        let ts = Utc.ymd(2020, 3, 1).and_hms_milli(1, 2, 3, 500);
+        let format = |offset| {
+            let mut buf = String::new();
+            format_timestamptz_in(&mut buf, ts, offset);
+            buf
+        };
+        assert_eq!(format(FixedOffset::east(0)), "2020-03-01 01:02:03.5+00");
+        assert_eq!(
+            format(FixedOffset::east(5 * 3600 + 30 * 60)),
+            "2020-03-01 06:32:03.5+05:30"
+        );
+        assert_eq!(
+            format(FixedOffset::west(8 * 3600)),
+            "2020-02-29 17:02:03.5-08"
+        );
+    }
+}
@@ -58,6 +58,7 @@ pub use self::query::{
     Cte, Fetch, Join, JoinConstraint, JoinOperator, OrderByExpr, Query, Select, SelectItem,
     SetExpr, SetOperator, TableAlias, TableFactor, TableWithJoins, Values,
 };
+pub(crate) use self::value::escape_quote;
 pub use self::value::{
     DateTimeField, DateTimeFieldValue, ExtractField, Interval, IntervalValue, ParsedDate,
     ParsedDateTime, ParsedTime, ParsedTimestamp, Value,
@@ -149,8 +150,10 @@ impl From<&str> for Ident {
 impl fmt::Display for Ident {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.quote_style {
-            Some(q) if q == '"' || q == '\'' || q == '`' => write!(f, "{}{}{}", q, self.value, q),
-            Some(q) if q == '[' => write!(f, "[{}]", self.value),
+            Some(q) if q == '"' || q == '\'' || q == '`' => {
+                write!(f, "{}{}{}", q, escape_quote(&self.value, q), q)
+            }
+            Some(q) if q == '[' => write!(f, "[{}]", escape_quote(&self.value, ']')),
             None => f.write_str(&self.value),
             _ => panic!("unexpected quote style"),
         }
@@ -773,6 +776,7 @@ pub enum Statement {
         materialized: bool,
         replace: bool,
         with_options: Vec<SqlOption>,
+        if_not_exists: bool,
     },
     /// `CREATE TABLE`
     CreateTable {
@@ -800,6 +804,15 @@ pub enum Statement {
         name: ObjectName,
         operation: AlterTableOperation,
     },
+    /// `ALTER SYSTEM SET <variable> = <value>`
+    AlterSystemSet {
+        variable: Ident,
+        value: SetVariableValue,
+    },
+    /// `ALTER SYSTEM RESET <variable>`
+    AlterSystemReset {
+        variable: Ident,
+    },
     DropDatabase {
         name: Ident,
         if_exists: bool,
@@ -897,10 +910,19 @@ pub enum Statement {
     Tail {
         name: ObjectName,
     },
-    /// `EXPLAIN [ DATAFLOW | PLAN ] FOR`
+    /// `EXPLAIN [ DATAFLOW | PLAN ] [ WITH (REDACTED) ] FOR`
     Explain {
         stage: Stage,
         query: Box<Query>,
+        /// Whether to replace literal values in the explanation with
+        /// placeholders.
+        redacted: bool,
+    },
+    /// `DISCARD { ALL | PLANS | SEQUENCES | TEMPORARY | TEMP }`
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    Discard {
+        target: DiscardTarget,
     },
 }
 
@@ -1037,6 +1059,7 @@ impl fmt::Display for Statement {
                 materialized,
                 replace,
                 with_options,
+                if_not_exists,
             } => {
                 write!(f, "CREATE")?;
                 if *replace {
@@ -1046,7 +1069,11 @@ impl fmt::Display for Statement {
                     write!(f, " MATERIALIZED")?;
                 }
 
-                write!(f, " VIEW {}", name)?;
+                write!(f, " VIEW")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                write!(f, " {}", name)?;
 
                 if !with_options.is_empty() {
                     write!(f, " WITH ({})", display_comma_separated(with_options))?;
@@ -1102,6 +1129,12 @@ impl fmt::Display for Statement {
             Statement::AlterTable { name, operation } => {
                 write!(f, "ALTER TABLE {} {}", name, operation)
             }
+            Statement::AlterSystemSet { variable, value } => {
+                write!(f, "ALTER SYSTEM SET {} = {}", variable, value)
+            }
+            Statement::AlterSystemReset { variable } => {
+                write!(f, "ALTER SYSTEM RESET {}", variable)
+            }
             Statement::DropDatabase { name, if_exists } => {
                 write!(f, "DROP DATABASE ")?;
                 if *if_exists {
@@ -1242,7 +1275,18 @@ impl fmt::Display for Statement {
                 write!(f, "ROLLBACK{}", if *chain { " AND CHAIN" } else { "" },)
             }
             Statement::Tail { name } => write!(f, "TAIL {}", name),
-            Statement::Explain { stage, query } => write!(f, "EXPLAIN {} FOR {}", stage, query),
+            Statement::Explain {
+                stage,
+                query,
+                redacted,
+            } => {
+                write!(f, "EXPLAIN {}", stage)?;
+                if *redacted {
+                    write!(f, " WITH (REDACTED)")?;
+                }
+                write!(f, " FOR {}", query)
+            }
+            Statement::Discard { target } => write!(f, "DISCARD {}", target),
         }
     }
 }
@@ -1373,6 +1417,26 @@ impl fmt::Display for TransactionIsolationLevel {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+pub enum DiscardTarget {
+    Plans,
+    Sequences,
+    Temp,
+    All,
+}
+
+impl fmt::Display for DiscardTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DiscardTarget::*;
+        f.write_str(match self {
+            Plans => "PLANS",
+            Sequences => "SEQUENCES",
+            Temp => "TEMP",
+            All => "ALL",
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ShowStatementFilter {
     Like(String),
@@ -77,7 +77,8 @@ pub enum SetExpr {
         right: Box<SetExpr>,
     },
     Values(Values),
-    // TODO: ANSI SQL supports `TABLE` here.
+    /// `TABLE <name>`, shorthand for `SELECT * FROM <name>`
+    Table(ObjectName),
 }
 
 impl fmt::Display for SetExpr {
@@ -86,6 +87,7 @@ impl fmt::Display for SetExpr {
             SetExpr::Select(s) => write!(f, "{}", s),
             SetExpr::Query(q) => write!(f, "({})", q),
             SetExpr::Values(v) => write!(f, "{}", v),
+            SetExpr::Table(name) => write!(f, "TABLE {}", name),
             SetExpr::SetOperation {
                 left,
                 right,
@@ -158,6 +158,27 @@ pub fn escape_single_quote_string(s: &str) -> EscapeSingleQuoteString<'_> {
     EscapeSingleQuoteString(s)
 }
 
+pub struct EscapeQuote<'a>(&'a str, char);
+
+impl<'a> fmt::Display for EscapeQuote<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.0.chars() {
+            if c == self.1 {
+                write!(f, "{}{}", c, c)?;
+            } else {
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Escapes occurrences of `quote` in `s` by doubling them, so that `s` can be
+/// written between quotes that end with `quote`.
+pub fn escape_quote(s: &str, quote: char) -> EscapeQuote<'_> {
+    EscapeQuote(s, quote)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -427,8 +427,9 @@ macro_rules! make_visitor {
                 materialized: bool,
                 replace: bool,
                 with_options: &'ast $($mut)* [SqlOption],
+                if_not_exists: bool,
             ) {
-                visit_create_view(self, name, columns, query, materialized, replace, with_options)
+                visit_create_view(self, name, columns, query, materialized, replace, with_options, if_not_exists)
             }
 
             fn visit_create_index(
@@ -503,6 +504,14 @@ macro_rules! make_visitor {
                 visit_alter_table_operation(self, operation)
             }
 
+            fn visit_alter_system_set(&mut self, variable: &'ast $($mut)* Ident, value: &'ast $($mut)* SetVariableValue) {
+                visit_alter_system_set(self, variable, value)
+            }
+
+            fn visit_alter_system_reset(&mut self, variable: &'ast $($mut)* Ident) {
+                visit_alter_system_reset(self, variable)
+            }
+
             fn visit_alter_add_constraint(&mut self, table_constraint: &'ast $($mut)* TableConstraint) {
                 visit_alter_add_constraint(self, table_constraint)
             }
@@ -632,9 +641,11 @@ macro_rules! make_visitor {
                 visit_tail(self, name)
             }
 
-            fn visit_explain(&mut self, stage: &'ast $($mut)* Stage, query: &'ast $($mut)* Query) {
-                visit_explain(self, stage, query)
+            fn visit_explain(&mut self, stage: &'ast $($mut)* Stage, query: &'ast $($mut)* Query, redacted: bool) {
+                visit_explain(self, stage, query, redacted)
             }
+
+            fn visit_discard(&mut self, _target: &'ast $($mut)* DiscardTarget) {}
         }
 
         pub fn visit_statement<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, statement: &'ast $($mut)* Statement) {
@@ -687,7 +698,8 @@ macro_rules! make_visitor {
                     materialized,
                     replace,
                     with_options,
-                } => visitor.visit_create_view(name, columns, query, *materialized, *replace, with_options),
+                    if_not_exists,
+                } => visitor.visit_create_view(name, columns, query, *materialized, *replace, with_options, *if_not_exists),
                 Statement::CreateIndex {
                     name,
                     on_name,
@@ -715,6 +727,8 @@ macro_rules! make_visitor {
                     *if_not_exists,
                 ),
                 Statement::AlterTable { name, operation } => visitor.visit_alter_table(name, operation),
+                Statement::AlterSystemSet { variable, value } => visitor.visit_alter_system_set(variable, value),
+                Statement::AlterSystemReset { variable } => visitor.visit_alter_system_reset(variable),
                 Statement::SetVariable {
                     local,
                     variable,
@@ -745,7 +759,12 @@ macro_rules! make_visitor {
                 Statement::Tail { name } => {
                     visitor.visit_tail(name);
                 }
-                Statement::Explain { stage, query } => visitor.visit_explain(stage, query),
+                Statement::Explain {
+                    stage,
+                    query,
+                    redacted,
+                } => visitor.visit_explain(stage, query, *redacted),
+                Statement::Discard { target } => visitor.visit_discard(target),
             }
         }
 
@@ -925,6 +944,7 @@ macro_rules! make_visitor {
                 SetExpr::Select(select) => visitor.visit_select(select),
                 SetExpr::Query(query) => visitor.visit_query(query),
                 SetExpr::Values(values) => visitor.visit_values(values),
+                SetExpr::Table(name) => visitor.visit_object_name(name),
                 SetExpr::SetOperation {
                     left,
                     op,
@@ -1455,6 +1475,7 @@ macro_rules! make_visitor {
             _materialized: bool,
             _replace: bool,
             with_options: &'ast $($mut)* [SqlOption],
+            _if_not_exists: bool,
         ) {
             visitor.visit_object_name(name);
             for column in columns {
@@ -1566,6 +1587,22 @@ macro_rules! make_visitor {
             }
         }
 
+        pub fn visit_alter_system_set<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            variable: &'ast $($mut)* Ident,
+            value: &'ast $($mut)* SetVariableValue,
+        ) {
+            visitor.visit_ident(variable);
+            visitor.visit_set_variable_value(value);
+        }
+
+        pub fn visit_alter_system_reset<'ast, V: $name<'ast> + ?Sized>(
+            visitor: &mut V,
+            variable: &'ast $($mut)* Ident,
+        ) {
+            visitor.visit_ident(variable);
+        }
+
         pub fn visit_alter_add_constraint<'ast, V: $name<'ast> + ?Sized>(
             visitor: &mut V,
             table_constraint: &'ast $($mut)* TableConstraint,
@@ -1787,7 +1824,7 @@ macro_rules! make_visitor {
             visitor.visit_object_name(name);
         }
 
-        pub fn visit_explain<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, _stage: &'ast $($mut)* Stage, query: &'ast $($mut)* Query) {
+        pub fn visit_explain<'ast, V: $name<'ast> + ?Sized>(visitor: &mut V, _stage: &'ast $($mut)* Stage, query: &'ast $($mut)* Query, _redacted: bool) {
             visitor.visit_query(query);
         }
     }
@@ -170,6 +170,7 @@ define_keywords!(
     DESC,
     DESCRIBE,
     DETERMINISTIC,
+    DISCARD,
     DISCONNECT,
     DISTINCT,
     DOUBLE,
@@ -331,6 +332,7 @@ define_keywords!(
     PERCENTILE_DISC,
     PERIOD,
     PLAN,
+    PLANS,
     PORTION,
     POSITION,
     POSITION_REGEX,
@@ -350,6 +352,7 @@ define_keywords!(
     READS,
     REAL,
     RECURSIVE,
+    REDACTED,
     REF,
     REFERENCES,
     REFERENCING,
@@ -368,6 +371,7 @@ define_keywords!(
     RELEASE,
     REPEATABLE,
     REPLACE,
+    RESET,
     RESTRICT,
     RESULT,
     RETURN,
@@ -390,6 +394,7 @@ define_keywords!(
     SEED,
     SELECT,
     SENSITIVE,
+    SEQUENCES,
     SERIALIZABLE,
     SESSION,
     SESSION_USER,
@@ -429,6 +434,8 @@ define_keywords!(
     TABLES,
     TABLESAMPLE,
     TAIL,
+    TEMP,
+    TEMPORARY,
     TEXT,
     THEN,
     TIES,
@@ -156,7 +156,7 @@ impl Parser {
         match self.next_token() {
             Some(t) => match t {
                 Token::Word(ref w) if w.keyword != "" => match w.keyword.as_ref() {
-                    "SELECT" | "WITH" | "VALUES" => {
+                    "SELECT" | "WITH" | "VALUES" | "TABLE" => {
                         self.prev_token();
                         Ok(Statement::Query(Box::new(self.parse_query()?)))
                     }
@@ -180,6 +180,7 @@ impl Parser {
                         name: self.parse_object_name()?,
                     }),
                     "EXPLAIN" => Ok(self.parse_explain()?),
+                    "DISCARD" => Ok(self.parse_discard()?),
                     _ => parser_err!(
                         self,
                         self.peek_prev_range(),
@@ -1665,6 +1666,15 @@ impl Parser {
         self.expect_keyword("VIEW")?;
         // Many dialects support `OR REPLACE` | `OR ALTER` right after `CREATE`, but we don't (yet).
         // ANSI SQL and Postgres support RECURSIVE here, but we don't support it either.
+        let if_not_exists_range = self.peek_range();
+        let if_not_exists = self.parse_if_not_exists()?;
+        if replace && if_not_exists {
+            return parser_err!(
+                self,
+                if_not_exists_range,
+                "cannot combine OR REPLACE and IF NOT EXISTS"
+            );
+        }
         let name = self.parse_object_name()?;
         let columns = self.parse_parenthesized_column_list(Optional)?;
         let with_options = self.parse_with_options()?;
@@ -1678,6 +1688,7 @@ impl Parser {
             materialized,
             replace,
             with_options,
+            if_not_exists,
         })
     }
 
@@ -1951,6 +1962,9 @@ impl Parser {
     }
 
     pub fn parse_alter(&mut self) -> Result<Statement, ParserError> {
+        if self.parse_keyword("SYSTEM") {
+            return self.parse_alter_system();
+        }
         self.expect_keyword("TABLE")?;
         let _ = self.parse_keyword("ONLY");
         let table_name = self.parse_object_name()?;
@@ -1977,6 +1991,23 @@ impl Parser {
         })
     }
 
+    fn parse_alter_system(&mut self) -> Result<Statement, ParserError> {
+        if self.parse_keyword("SET") {
+            let variable = self.parse_identifier()?;
+            if !self.consume_token(&Token::Eq) && !self.parse_keyword("TO") {
+                return self.expected(self.peek_range(), "equals sign or TO", self.peek_token());
+            }
+            let value = self.parse_set_variable_value()?;
+            Ok(Statement::AlterSystemSet { variable, value })
+        } else if self.parse_keyword("RESET") {
+            Ok(Statement::AlterSystemReset {
+                variable: self.parse_identifier()?,
+            })
+        } else {
+            self.expected(self.peek_range(), "SET or RESET", self.peek_token())
+        }
+    }
+
     /// Parse a copy statement
     pub fn parse_copy(&mut self) -> Result<Statement, ParserError> {
         let table_name = self.parse_object_name()?;
@@ -2412,10 +2443,12 @@ impl Parser {
             SetExpr::Query(Box::new(subquery))
         } else if self.parse_keyword("VALUES") {
             SetExpr::Values(self.parse_values()?)
+        } else if self.parse_keyword("TABLE") {
+            SetExpr::Table(self.parse_object_name()?)
         } else {
             return self.expected(
                 self.peek_range(),
-                "SELECT, VALUES, or a subquery in the query body",
+                "SELECT, VALUES, TABLE, or a subquery in the query body",
                 self.peek_token(),
             );
         };
@@ -2513,14 +2546,16 @@ impl Parser {
 
     pub fn parse_set(&mut self) -> Result<Statement, ParserError> {
         let modifier = self.parse_one_of_keywords(&["SESSION", "LOCAL"]);
+        if self.parse_keywords(vec!["TIME", "ZONE"]) {
+            return Ok(Statement::SetVariable {
+                local: modifier == Some("LOCAL"),
+                variable: Ident::new("timezone"),
+                value: self.parse_set_variable_value()?,
+            });
+        }
         let variable = self.parse_identifier()?;
         if self.consume_token(&Token::Eq) || self.parse_keyword("TO") {
-            let token = self.peek_token();
-            let value = match (self.parse_value(), token) {
-                (Ok(value), _) => SetVariableValue::Literal(value),
-                (Err(_), Some(Token::Word(ident))) => SetVariableValue::Ident(ident.to_ident()),
-                (Err(_), other) => self.expected(self.peek_range(), "variable value", other)?,
-            };
+            let value = self.parse_set_variable_value()?;
             Ok(Statement::SetVariable {
                 local: modifier == Some("LOCAL"),
                 variable,
@@ -2535,6 +2570,15 @@ impl Parser {
         }
     }
 
+    fn parse_set_variable_value(&mut self) -> Result<SetVariableValue, ParserError> {
+        let token = self.peek_token();
+        match (self.parse_value(), token) {
+            (Ok(value), _) => Ok(SetVariableValue::Literal(value)),
+            (Err(_), Some(Token::Word(ident))) => Ok(SetVariableValue::Ident(ident.to_ident())),
+            (Err(_), other) => self.expected(self.peek_range(), "variable value", other),
+        }
+    }
+
     pub fn parse_show(&mut self) -> Result<Statement, ParserError> {
         if self.parse_keyword("DATABASES") {
             return Ok(Statement::ShowDatabases {
@@ -2633,6 +2677,14 @@ impl Parser {
             Ok(Statement::ShowCreateSource {
                 source_name: self.parse_object_name()?,
             })
+        } else if self.parse_keywords(vec!["TRANSACTION", "ISOLATION", "LEVEL"]) {
+            Ok(Statement::ShowVariable {
+                variable: Ident::new("transaction_isolation"),
+            })
+        } else if self.parse_keywords(vec!["TIME", "ZONE"]) {
+            Ok(Statement::ShowVariable {
+                variable: Ident::new("timezone"),
+            })
         } else {
             Ok(Statement::ShowVariable {
                 variable: self.parse_identifier()?,
@@ -3046,6 +3098,23 @@ impl Parser {
         Ok(modes)
     }
 
+    pub fn parse_discard(&mut self) -> Result<Statement, ParserError> {
+        let target = match self.expect_one_of_keywords(&[
+            "ALL",
+            "PLANS",
+            "SEQUENCES",
+            "TEMPORARY",
+            "TEMP",
+        ])? {
+            "ALL" => DiscardTarget::All,
+            "PLANS" => DiscardTarget::Plans,
+            "SEQUENCES" => DiscardTarget::Sequences,
+            "TEMPORARY" | "TEMP" => DiscardTarget::Temp,
+            _ => unreachable!(),
+        };
+        Ok(Statement::Discard { target })
+    }
+
     pub fn parse_commit(&mut self) -> Result<Statement, ParserError> {
         Ok(Statement::Commit {
             chain: self.parse_commit_rollback_chain()?,
@@ -3079,11 +3148,20 @@ impl Parser {
         } else {
             self.expected(self.peek_range(), "DATAFLOW or PLAN", self.peek_token())?
         };
+        let redacted = if self.parse_keyword("WITH") {
+            self.expect_token(&Token::LParen)?;
+            self.expect_keyword("REDACTED")?;
+            self.expect_token(&Token::RParen)?;
+            true
+        } else {
+            false
+        };
         self.expect_keyword("FOR")?;
 
         Ok(Statement::Explain {
             stage,
             query: Box::new(self.parse_query()?),
+            redacted,
         })
     }
 }
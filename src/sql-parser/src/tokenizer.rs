@@ -27,6 +27,7 @@
 use std::fmt;
 use std::ops::Range;
 
+use crate::ast::escape_quote;
 use crate::keywords::ALL_KEYWORDS;
 use crate::parser::ParserError;
 
@@ -218,7 +219,8 @@ impl fmt::Display for Word {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.quote_style {
             Some(s) if s == '"' || s == '[' || s == '`' => {
-                write!(f, "{}{}{}", s, self.value, Word::matching_end_quote(s))
+                let end = Word::matching_end_quote(s);
+                write!(f, "{}{}{}", s, escape_quote(&self.value, end), end)
             }
             None => f.write_str(&self.value),
             _ => panic!("Unexpected quote_style!"),
@@ -226,7 +228,7 @@ impl fmt::Display for Word {
     }
 }
 impl Word {
-    fn matching_end_quote(ch: char) -> char {
+    pub(crate) fn matching_end_quote(ch: char) -> char {
         match ch {
             '"' => '"', // ANSI and most dialects
             '[' => ']', // MS SQL
@@ -366,14 +368,27 @@ impl Tokenizer {
                 quote_start if self.is_delimited_identifier_start(quote_start) => {
                     chars.next(); // consume the opening quote
                     let quote_end = Word::matching_end_quote(quote_start);
-                    let s = peeking_take_while(chars, |ch| ch != quote_end);
-                    if chars.next() == Some(quote_end) {
-                        Ok(Some(Token::make_word(&s, Some(quote_start))))
-                    } else {
-                        Err(format!(
-                            "Expected close delimiter '{}' before EOF.",
-                            quote_end
-                        ))
+                    let mut s = String::new();
+                    loop {
+                        match chars.next() {
+                            // A doubled closing quote is an escaped quote
+                            // character, not the end of the identifier.
+                            Some(ch) if ch == quote_end => {
+                                if chars.peek() == Some(quote_end) {
+                                    chars.next();
+                                    s.push(quote_end);
+                                } else {
+                                    break Ok(Some(Token::make_word(&s, Some(quote_start))));
+                                }
+                            }
+                            Some(ch) => s.push(ch),
+                            None => {
+                                break Err(format!(
+                                    "Expected close delimiter '{}' before EOF.",
+                                    quote_end
+                                ))
+                            }
+                        }
                     }
                 }
                 // numbers
@@ -974,6 +989,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tokenize_escaped_quotes() {
+        let sql = String::from(r#""a""b" "c""""#);
+
+        let mut tokenizer = Tokenizer::new(&sql);
+        let tokens = tokenizer
+            .tokenize()
+            .unwrap()
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect::<Vec<_>>();
+        let expected = vec![
+            Token::make_word(r#"a"b"#, Some('"')),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word(r#"c""#, Some('"')),
+        ];
+        compare(expected, tokens);
+        assert_eq!(
+            Token::make_word(r#"a"b"#, Some('"')).to_string(),
+            r#""a""b""#
+        );
+    }
+
     #[test]
     fn tokenize_newlines() {
         let sql = String::from("line1\nline2\rline3\r\nline4\r");
@@ -2296,6 +2296,27 @@ fn parse_delimited_identifiers() {
     //TODO verified_stmt(r#"UPDATE foo SET "bar" = 5"#);
 }
 
+#[test]
+fn parse_delimited_identifiers_with_escaped_quotes() {
+    let select = verified_only_select(r#"SELECT "a""b", "c""" FROM "d""""e""#);
+    assert_eq!(
+        &Expr::Identifier(Ident::with_quote('"', r#"a"b"#)),
+        expr_from_projection(&select.projection[0]),
+    );
+    assert_eq!(
+        &Expr::Identifier(Ident::with_quote('"', r#"c""#)),
+        expr_from_projection(&select.projection[1]),
+    );
+    match only(select.from).relation {
+        TableFactor::Table { name, .. } => {
+            assert_eq!(vec![Ident::with_quote('"', r#"d""e"#)], name.0);
+        }
+        _ => panic!("Expecting TableFactor::Table"),
+    }
+
+    verified_stmt(r#"CREATE VIEW "my ""view""" AS SELECT 1"#);
+}
+
 #[test]
 fn parse_parens() {
     use self::BinaryOperator::*;
@@ -3163,6 +3184,16 @@ fn parse_values() {
     verified_stmt("SELECT * FROM (VALUES (1)) UNION VALUES (1)");
 }
 
+#[test]
+fn parse_table() {
+    match verified_query("TABLE foo.bar").body {
+        SetExpr::Table(name) => assert_eq!("foo.bar", name.to_string()),
+        _ => unreachable!(),
+    }
+    verified_stmt("TABLE a UNION TABLE b ORDER BY 1");
+    verified_stmt("SELECT * FROM (TABLE a)");
+}
+
 #[test]
 fn parse_multiple_statements() {
     fn test_with(sql1: &str, sql2_kw: &str, sql2_rest: &str) {
@@ -3309,7 +3340,7 @@ fn parse_exists_subquery() {
 Parse error:
 SELECT EXISTS (
                ^
-Expected SELECT, VALUES, or a subquery in the query body, found: EOF"
+Expected SELECT, VALUES, TABLE, or a subquery in the query body, found: EOF"
             .to_string()),
         format!("{}", res.unwrap_err(),)
     );
@@ -3320,7 +3351,7 @@ Expected SELECT, VALUES, or a subquery in the query body, found: EOF"
 Parse error:
 SELECT EXISTS (NULL)
                ^^^^
-Expected SELECT, VALUES, or a subquery in the query body, found: NULL"
+Expected SELECT, VALUES, TABLE, or a subquery in the query body, found: NULL"
             .to_string()),
         format!("{}", res.unwrap_err(),)
     );
@@ -3405,6 +3436,7 @@ fn parse_create_view() {
             materialized,
             replace,
             with_options,
+            if_not_exists,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
@@ -3412,6 +3444,7 @@ fn parse_create_view() {
             assert!(!materialized);
             assert!(!replace);
             assert_eq!(with_options, vec![]);
+            assert!(!if_not_exists);
         }
         _ => unreachable!(),
     }
@@ -3426,6 +3459,38 @@ fn parse_create_or_replace_view() {
     }
 }
 
+#[test]
+fn parse_create_view_if_not_exists() {
+    let sql = "CREATE MATERIALIZED VIEW IF NOT EXISTS v AS SELECT 1";
+    match verified_stmt(sql) {
+        Statement::CreateView {
+            name,
+            materialized,
+            replace,
+            if_not_exists,
+            ..
+        } => {
+            assert_eq!("v", name.to_string());
+            assert!(materialized);
+            assert!(!replace);
+            assert!(if_not_exists);
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE OR REPLACE VIEW IF NOT EXISTS v AS SELECT 1";
+    let res = parse_sql_statements(sql);
+    assert_eq!(
+        ("\
+Parse error:
+CREATE OR REPLACE VIEW IF NOT EXISTS v AS SELECT 1
+                       ^^
+cannot combine OR REPLACE and IF NOT EXISTS"
+            .to_string()),
+        format!("{}", res.unwrap_err())
+    );
+}
+
 #[test]
 fn parse_create_view_with_options() {
     let sql = "CREATE VIEW v WITH (foo = 'bar', a = 123) AS SELECT 1";
@@ -3460,6 +3525,7 @@ fn parse_create_view_with_columns() {
             query,
             materialized,
             replace,
+            if_not_exists,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![Ident::new("has"), Ident::new("cols")]);
@@ -3467,6 +3533,7 @@ fn parse_create_view_with_columns() {
             assert_eq!("SELECT 1, 2", query.to_string());
             assert!(!materialized);
             assert!(!replace);
+            assert!(!if_not_exists);
         }
         _ => unreachable!(),
     }
@@ -3483,6 +3550,7 @@ fn parse_create_materialized_view() {
             materialized,
             replace,
             with_options,
+            if_not_exists,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
@@ -3490,6 +3558,7 @@ fn parse_create_materialized_view() {
             assert!(materialized);
             assert!(!replace);
             assert_eq!(with_options, vec![]);
+            assert!(!if_not_exists);
         }
         _ => unreachable!(),
     }
@@ -4418,7 +4487,7 @@ Expected subquery after LATERAL, found: generate_series"
 Parse error:
 SELECT * FROM a LEFT JOIN LATERAL (b CROSS JOIN c)
                                    ^
-Expected SELECT, VALUES, or a subquery in the query body, found: b"
+Expected SELECT, VALUES, TABLE, or a subquery in the query body, found: b"
             .to_string()),
         format!("{}", res.unwrap_err())
     );
@@ -4559,6 +4628,50 @@ fn parse_rollback() {
     one_statement_parses_to("ROLLBACK TRANSACTION", "ROLLBACK");
 }
 
+#[test]
+fn parse_discard() {
+    match verified_stmt("DISCARD ALL") {
+        Statement::Discard {
+            target: DiscardTarget::All,
+        } => (),
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("DISCARD PLANS") {
+        Statement::Discard {
+            target: DiscardTarget::Plans,
+        } => (),
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("DISCARD SEQUENCES") {
+        Statement::Discard {
+            target: DiscardTarget::Sequences,
+        } => (),
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("DISCARD TEMP") {
+        Statement::Discard {
+            target: DiscardTarget::Temp,
+        } => (),
+        _ => unreachable!(),
+    }
+
+    one_statement_parses_to("DISCARD TEMPORARY", "DISCARD TEMP");
+
+    let res = parse_sql_statements("DISCARD EVERYTHING");
+    assert_eq!(
+        ("\
+Parse error:
+DISCARD EVERYTHING
+        ^^^^^^^^^^
+Expected one of ALL or PLANS or SEQUENCES or TEMPORARY or TEMP, found: EVERYTHING"
+            .to_string()),
+        format!("{}", res.unwrap_err())
+    );
+}
+
 #[test]
 fn parse_explain() {
     let ast = verified_stmt("EXPLAIN DATAFLOW FOR SELECT 665");
@@ -4567,6 +4680,7 @@ fn parse_explain() {
         Statement::Explain {
             stage: Stage::Dataflow,
             query: Box::new(verified_query("SELECT 665")),
+            redacted: false,
         }
     );
 
@@ -4576,6 +4690,17 @@ fn parse_explain() {
         Statement::Explain {
             stage: Stage::Plan,
             query: Box::new(verified_query("SELECT 665")),
+            redacted: false,
+        }
+    );
+
+    let ast = verified_stmt("EXPLAIN PLAN WITH (REDACTED) FOR SELECT 665");
+    assert_eq!(
+        ast,
+        Statement::Explain {
+            stage: Stage::Plan,
+            query: Box::new(verified_query("SELECT 665")),
+            redacted: true,
         }
     );
 }
@@ -4964,6 +5089,7 @@ fn parse_set() {
 
     one_statement_parses_to("SET a TO b", "SET a = b");
     one_statement_parses_to("SET SESSION a = b", "SET a = b");
+    one_statement_parses_to("SET TIME ZONE '+05:30'", "SET timezone = '+05:30'");
 
     assert_eq!(
         parse_sql_statements("SET").unwrap_err().to_string(),
@@ -4996,6 +5122,40 @@ Expected variable value, found: EOF"
     );
 }
 
+#[test]
+fn parse_alter_system() {
+    let stmt = verified_stmt("ALTER SYSTEM SET maintenance_mode = 'upgrading'");
+    assert_eq!(
+        stmt,
+        Statement::AlterSystemSet {
+            variable: "maintenance_mode".into(),
+            value: SetVariableValue::Literal(Value::SingleQuotedString("upgrading".into())),
+        }
+    );
+
+    let stmt = verified_stmt("ALTER SYSTEM RESET maintenance_mode");
+    assert_eq!(
+        stmt,
+        Statement::AlterSystemReset {
+            variable: "maintenance_mode".into(),
+        }
+    );
+
+    one_statement_parses_to("ALTER SYSTEM SET a TO b", "ALTER SYSTEM SET a = b");
+
+    assert_eq!(
+        parse_sql_statements("ALTER SYSTEM a")
+            .unwrap_err()
+            .to_string(),
+        "\
+Parse error:
+ALTER SYSTEM a
+             ^
+Expected SET or RESET, found: a"
+            .to_string(),
+    );
+}
+
 #[test]
 fn parse_show() {
     let stmt = verified_stmt("SHOW a");
@@ -5012,7 +5172,13 @@ fn parse_show() {
         Statement::ShowVariable {
             variable: "ALL".into()
         }
-    )
+    );
+
+    one_statement_parses_to(
+        "SHOW TRANSACTION ISOLATION LEVEL",
+        "SHOW transaction_isolation",
+    );
+    one_statement_parses_to("SHOW TIME ZONE", "SHOW timezone");
 }
 
 #[test]
@@ -19,8 +19,10 @@ use ore::future::MaybeFuture;
 use repr::{RelationDesc, Row, ScalarType};
 use sql_parser::parser::Parser as SqlParser;
 
-pub use session::{InternalSession, PlanSession, PreparedStatement, Session, TransactionStatus};
-pub use sql_parser::ast::{ObjectType, Statement};
+pub use session::{
+    InternalSession, PlanSession, PreparedStatement, Session, TransactionStatus, SYSTEM_USER,
+};
+pub use sql_parser::ast::{DiscardTarget, ObjectType, Statement};
 pub use statement::StatementContext;
 
 pub mod normalize;
@@ -70,6 +72,7 @@ pub enum Plan {
         replace: Option<GlobalId>,
         /// whether we should auto-materialize the view
         materialize: bool,
+        if_not_exists: bool,
     },
     CreateIndex {
         name: FullName,
@@ -107,6 +110,16 @@ pub enum Plan {
     /// We don't do anything for transactions, so other than changing the session state
     /// this is a no-op
     Rollback,
+    /// Discard session state, as in PostgreSQL's `DISCARD` statement
+    ///
+    /// Only `DISCARD ALL` has any effect: Materialize has no temporary objects,
+    /// sequences, or cached plans to discard.
+    Discard(DiscardTarget),
+    /// Enter maintenance mode with the given message, or leave it if
+    /// `message` is `None`.
+    SetMaintenanceMode {
+        message: Option<String>,
+    },
     Peek {
         source: ::expr::RelationExpr,
         when: PeekWhen,
@@ -115,7 +128,11 @@ pub enum Plan {
     },
     Tail(CatalogEntry),
     SendRows(Vec<Row>),
-    ExplainPlan(::expr::RelationExpr),
+    ExplainPlan {
+        relation_expr: ::expr::RelationExpr,
+        /// Whether to redact literals after optimizing `relation_expr`.
+        redacted: bool,
+    },
     SendDiffs {
         id: GlobalId,
         updates: Vec<(Row, isize)>,
@@ -130,6 +147,40 @@ pub enum Plan {
     },
 }
 
+impl Plan {
+    /// Reports whether executing this plan modifies the catalog or the
+    /// contents of a table.
+    pub fn mutates(&self) -> bool {
+        match self {
+            Plan::CreateDatabase { .. }
+            | Plan::CreateSchema { .. }
+            | Plan::CreateSource { .. }
+            | Plan::CreateSink { .. }
+            | Plan::CreateTable { .. }
+            | Plan::CreateView { .. }
+            | Plan::CreateIndex { .. }
+            | Plan::DropDatabase { .. }
+            | Plan::DropSchema { .. }
+            | Plan::DropItems { .. }
+            | Plan::SendDiffs { .. } => true,
+            Plan::EmptyQuery
+            | Plan::ShowAllVariables
+            | Plan::ShowVariable(_)
+            | Plan::SetVariable { .. }
+            | Plan::StartTransaction
+            | Plan::Commit
+            | Plan::Rollback
+            | Plan::Discard(_)
+            | Plan::SetMaintenanceMode { .. }
+            | Plan::Peek { .. }
+            | Plan::Tail(_)
+            | Plan::SendRows(_)
+            | Plan::ExplainPlan { .. }
+            | Plan::ShowViews { .. } => false,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Source {
     pub create_sql: String,
@@ -7,13 +7,14 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use failure::bail;
+use failure::{bail, format_err};
 
 use catalog::names::{DatabaseSpecifier, FullName, PartialName};
 use ore::collections::CollectionExt;
 use repr::ColumnName;
 use sql_parser::ast::visit_mut::VisitMut;
 use sql_parser::ast::{Expr, Function, Ident, ObjectName, Statement, TableAlias};
+use sql_parser::parser::Parser as SqlParser;
 
 use crate::statement::StatementContext;
 
@@ -74,10 +75,60 @@ pub fn unresolve(name: FullName) -> ObjectName {
 /// The goal is to construct a backwards-compatible description of the object.
 /// SQL is the most stable part of Materialize, so SQL is used to describe the
 /// objects that are persisted in the catalog.
-pub fn create_statement(
+///
+/// The normalized statement is checked with [`verify_rewrite`] before it is
+/// returned.
+pub fn create_statement(scx: &StatementContext, stmt: Statement) -> Result<String, failure::Error> {
+    let create_sql = normalize_create_statement(scx, stmt)?.to_string();
+    verify_rewrite(scx, &create_sql)?;
+    Ok(create_sql)
+}
+
+/// Verifies that `create_sql`, as produced by [`create_statement`], reparses
+/// as exactly one statement, and that normalizing that statement again, which
+/// re-resolves every object name it references, reproduces `create_sql`
+/// exactly.
+///
+/// A failure here indicates a bug in the normalizer, not in the user's SQL,
+/// and is reported as an internal error so that the corrupt SQL is never
+/// persisted to the catalog.
+pub fn verify_rewrite(scx: &StatementContext, create_sql: &str) -> Result<(), failure::Error> {
+    let stmt = match SqlParser::parse_sql(create_sql.into()) {
+        Ok(mut stmts) if stmts.len() == 1 => stmts.remove(0),
+        Ok(stmts) => bail!(
+            "internal error: normalized statement reparsed as {} statements: {}",
+            stmts.len(),
+            create_sql
+        ),
+        Err(e) => bail!(
+            "internal error: normalized statement does not reparse: {}: {}",
+            create_sql,
+            e
+        ),
+    };
+    let renormalized = normalize_create_statement(scx, stmt)
+        .map_err(|e| {
+            format_err!(
+                "internal error: normalized statement does not resolve: {}: {}",
+                create_sql,
+                e
+            )
+        })?
+        .to_string();
+    if renormalized != create_sql {
+        bail!(
+            "internal error: normalized statement is not stable: {} became {}",
+            create_sql,
+            renormalized
+        );
+    }
+    Ok(())
+}
+
+fn normalize_create_statement(
     scx: &StatementContext,
     mut stmt: Statement,
-) -> Result<String, failure::Error> {
+) -> Result<Statement, failure::Error> {
     fn norm_ident(ident: &mut Ident) {
         if ident.quote_style.is_none() {
             ident.value = ident.value.to_lowercase();
@@ -188,6 +239,7 @@ pub fn create_statement(
             materialized,
             replace,
             with_options: _,
+            if_not_exists,
         } => {
             *name = allocate_name(name)?;
             for c in columns {
@@ -202,6 +254,7 @@ pub fn create_statement(
             }
             *materialized = false;
             *replace = false;
+            *if_not_exists = false;
         }
 
         Statement::CreateIndex {
@@ -225,5 +278,5 @@ pub fn create_statement(
         _ => unreachable!(),
     }
 
-    Ok(stmt.to_string())
+    Ok(stmt)
 }
@@ -370,6 +370,25 @@ fn plan_set_expr(qcx: &QueryContext, q: &SetExpr) -> Result<(RelationExpr, Scope
             let (expr, scope) = plan_subquery(qcx, query)?;
             Ok((expr, scope))
         }
+        SetExpr::Table(name) => {
+            let select = Select {
+                distinct: false,
+                projection: vec![SelectItem::Wildcard],
+                from: vec![TableWithJoins {
+                    relation: TableFactor::Table {
+                        name: name.clone(),
+                        alias: None,
+                        args: vec![],
+                        with_hints: vec![],
+                    },
+                    joins: vec![],
+                }],
+                selection: None,
+                group_by: vec![],
+                having: None,
+            };
+            plan_view_select(qcx, &select)
+        }
     }
 }
 
@@ -37,6 +37,6 @@ mod statement;
 mod transaction;
 mod var;
 
-pub use session::{InternalSession, PlanSession, Session};
+pub use session::{InternalSession, PlanSession, Session, SYSTEM_USER};
 pub use statement::{Portal, PreparedStatement};
 pub use transaction::TransactionStatus;
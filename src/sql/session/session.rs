@@ -18,8 +18,10 @@
 
 use std::collections::HashMap;
 use std::fmt;
+use std::mem;
 
-use failure::bail;
+use chrono::FixedOffset;
+use failure::{bail, format_err};
 
 use catalog::names::DatabaseSpecifier;
 use repr::{Datum, Row, ScalarType};
@@ -60,6 +62,13 @@ const EXTRA_FLOAT_DIGITS: ServerVar<&i32> = ServerVar {
     description: "Adjusts the number of digits displayed for floating-point values (PostgreSQL).",
 };
 
+const MAX_CROSS_JOIN_ROWS: ServerVar<&i32> = ServerVar {
+    name: unicase::Ascii::new("max_cross_join_rows"),
+    value: &0,
+    description:
+        "Rejects cross joins that may produce more rows than this; 0 disables (Materialize).",
+};
+
 const SEARCH_PATH: ServerVar<&[&str]> = ServerVar {
     name: unicase::Ascii::new("search_path"),
     value: &["mz_catalog", "pg_catalog", "public"],
@@ -77,12 +86,42 @@ const SERVER_VERSION: ServerVar<&str> = ServerVar {
     description: "Shows the server version (PostgreSQL).",
 };
 
+const SERVER_VERSION_NUM: ServerVar<&str> = ServerVar {
+    name: unicase::Ascii::new("server_version_num"),
+    // Must agree with SERVER_VERSION.
+    value: "90500",
+    description: "Shows the server version as an integer (PostgreSQL).",
+};
+
 const SQL_SAFE_UPDATES: ServerVar<&bool> = ServerVar {
     name: unicase::Ascii::new("sql_safe_updates"),
     value: &false,
     description: "Prohibits SQL statements that may be overly destructive (CockroachDB).",
 };
 
+const STANDARD_CONFORMING_STRINGS: ServerVar<&str> = ServerVar {
+    name: unicase::Ascii::new("standard_conforming_strings"),
+    value: "on",
+    description: "Causes '...' strings to treat backslashes literally (PostgreSQL).",
+};
+
+const TIMEZONE: ServerVar<&str> = ServerVar {
+    // TimeZone has nonstandard capitalization for historical reasons.
+    name: unicase::Ascii::new("TimeZone"),
+    value: "UTC",
+    description: "Sets the time zone for displaying and interpreting time stamps (PostgreSQL).",
+};
+
+const TRANSACTION_ISOLATION: ServerVar<&str> = ServerVar {
+    name: unicase::Ascii::new("transaction_isolation"),
+    value: "serializable",
+    description: "Sets the current transaction's isolation level (PostgreSQL).",
+};
+
+/// The name of the user that is permitted to change system-wide configuration,
+/// e.g. via `ALTER SYSTEM`.
+pub const SYSTEM_USER: &str = "mz_system";
+
 /// A `Session` holds SQL state that is attached to a session.
 pub struct Session {
     application_name: SessionVar<str>,
@@ -90,9 +129,14 @@ pub struct Session {
     database: SessionVar<str>,
     date_style: ServerVar<&'static str>,
     extra_float_digits: SessionVar<i32>,
+    max_cross_join_rows: SessionVar<i32>,
     search_path: ServerVar<&'static [&'static str]>,
     server_version: ServerVar<&'static str>,
+    server_version_num: ServerVar<&'static str>,
     sql_safe_updates: SessionVar<bool>,
+    standard_conforming_strings: ServerVar<&'static str>,
+    timezone: SessionVar<str>,
+    transaction_isolation: ServerVar<&'static str>,
     /// The current state of the the session's transaction
     transaction: TransactionStatus,
     /// A map from statement names to SQL queries
@@ -102,6 +146,11 @@ pub struct Session {
     /// Portals are primarily a way to retrieve the results for a query with all
     /// parameters bound.
     portals: HashMap<String, Portal>,
+    /// Configuration parameters that were specified by the client at connection
+    /// startup, which are restored by [`Session::reset`].
+    startup_params: Vec<(String, String)>,
+    /// The name of the user that the client connected as.
+    user: String,
 }
 
 impl fmt::Debug for Session {
@@ -112,10 +161,19 @@ impl fmt::Debug for Session {
             .field("database", &self.database())
             .field("date_style", &self.date_style())
             .field("extra_float_digits", &self.extra_float_digits())
+            .field("max_cross_join_rows", &self.max_cross_join_rows())
             .field("search_path", &self.search_path())
             .field("server_version", &self.server_version())
+            .field("server_version_num", &self.server_version_num())
             .field("sql_safe_updates", &self.sql_safe_updates())
+            .field(
+                "standard_conforming_strings",
+                &self.standard_conforming_strings(),
+            )
+            .field("timezone", &self.timezone())
+            .field("transaction_isolation", &self.transaction_isolation())
             .field("transaction", &self.transaction())
+            .field("user", &self.user())
             .field("prepared_statements", &self.prepared_statements.keys())
             .field("portals", &self.portals.keys())
             .finish()
@@ -131,12 +189,19 @@ impl Default for Session {
             database: SessionVar::new(&DATABASE),
             date_style: DATE_STYLE,
             extra_float_digits: SessionVar::new(&EXTRA_FLOAT_DIGITS),
+            max_cross_join_rows: SessionVar::new(&MAX_CROSS_JOIN_ROWS),
             search_path: SEARCH_PATH,
             server_version: SERVER_VERSION,
+            server_version_num: SERVER_VERSION_NUM,
             sql_safe_updates: SessionVar::new(&SQL_SAFE_UPDATES),
+            standard_conforming_strings: STANDARD_CONFORMING_STRINGS,
+            timezone: SessionVar::new(&TIMEZONE),
+            transaction_isolation: TRANSACTION_ISOLATION,
             transaction: TransactionStatus::Idle,
             prepared_statements: HashMap::new(),
             portals: HashMap::new(),
+            startup_params: vec![],
+            user: String::new(),
         }
     }
 }
@@ -151,9 +216,14 @@ impl Session {
             &self.database,
             &self.date_style,
             &self.extra_float_digits,
+            &self.max_cross_join_rows,
             &self.search_path,
             &self.server_version,
+            &self.server_version_num,
             &self.sql_safe_updates,
+            &self.standard_conforming_strings,
+            &self.timezone,
+            &self.transaction_isolation,
         ]
     }
 
@@ -166,6 +236,8 @@ impl Session {
             &self.client_encoding,
             &self.date_style,
             &self.server_version,
+            &self.standard_conforming_strings,
+            &self.timezone,
         ]
     }
 
@@ -190,12 +262,22 @@ impl Session {
             Ok(&self.date_style)
         } else if name == EXTRA_FLOAT_DIGITS.name {
             Ok(&self.extra_float_digits)
+        } else if name == MAX_CROSS_JOIN_ROWS.name {
+            Ok(&self.max_cross_join_rows)
         } else if name == SEARCH_PATH.name {
             Ok(&self.search_path)
         } else if name == SERVER_VERSION.name {
             Ok(&self.server_version)
+        } else if name == SERVER_VERSION_NUM.name {
+            Ok(&self.server_version_num)
         } else if name == SQL_SAFE_UPDATES.name {
             Ok(&self.sql_safe_updates)
+        } else if name == STANDARD_CONFORMING_STRINGS.name {
+            Ok(&self.standard_conforming_strings)
+        } else if name == TIMEZONE.name {
+            Ok(&self.timezone)
+        } else if name == TRANSACTION_ISOLATION.name {
+            Ok(&self.transaction_isolation)
         } else {
             bail!("unknown parameter: {}", name)
         }
@@ -219,17 +301,83 @@ impl Session {
             bail!("parameter {} is read only", DATE_STYLE.name);
         } else if name == EXTRA_FLOAT_DIGITS.name {
             self.extra_float_digits.set(value)
+        } else if name == MAX_CROSS_JOIN_ROWS.name {
+            if let Ok(n) = value.parse::<i32>() {
+                if n < 0 {
+                    bail!(
+                        "parameter {} must not be negative",
+                        MAX_CROSS_JOIN_ROWS.name
+                    );
+                }
+            }
+            self.max_cross_join_rows.set(value)
         } else if name == SEARCH_PATH.name {
             bail!("parameter {} is read only", SEARCH_PATH.name);
         } else if name == SERVER_VERSION.name {
             bail!("parameter {} is read only", SERVER_VERSION.name);
+        } else if name == SERVER_VERSION_NUM.name {
+            bail!("parameter {} is read only", SERVER_VERSION_NUM.name);
         } else if name == SQL_SAFE_UPDATES.name {
             self.sql_safe_updates.set(value)
+        } else if name == STANDARD_CONFORMING_STRINGS.name {
+            bail!(
+                "parameter {} is read only",
+                STANDARD_CONFORMING_STRINGS.name
+            );
+        } else if name == TIMEZONE.name {
+            parse_timezone(value)?;
+            self.timezone.set(value)
+        } else if name == TRANSACTION_ISOLATION.name {
+            bail!("parameter {} is read only", TRANSACTION_ISOLATION.name);
         } else {
             bail!("unknown parameter: {}", name)
         }
     }
 
+    /// Sets the configuration parameter named `name` to the value represented
+    /// by `value`, as requested by the client at connection startup.
+    ///
+    /// Behaves like [`Session::set`], except that the value is remembered as
+    /// the parameter's default for the remainder of the session, and so
+    /// survives a call to [`Session::reset`]. The `user` parameter is also
+    /// accepted, and sets the user that the session is connected as.
+    pub fn set_startup_param(&mut self, name: &str, value: &str) -> Result<(), failure::Error> {
+        if name == "user" {
+            self.user = value.to_owned();
+        } else {
+            self.set(name, value)?;
+        }
+        self.startup_params
+            .push((name.to_owned(), value.to_owned()));
+        Ok(())
+    }
+
+    /// Resets the session to the state it was in immediately after connection
+    /// startup, as with PostgreSQL's `DISCARD ALL`.
+    ///
+    /// All prepared statements and portals are dropped, and all configuration
+    /// parameters are restored to their startup values.
+    pub fn reset(&mut self) {
+        let startup_params = mem::replace(&mut self.startup_params, vec![]);
+        *self = Session::default();
+        for (name, value) in startup_params {
+            // The parameter was valid when it was first set, so it is still
+            // valid now.
+            self.set_startup_param(&name, &value)
+                .expect("startup parameter unexpectedly invalid");
+        }
+    }
+
+    /// Returns the name of the user that the session is connected as.
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    /// Reports whether the session is connected as the [`SYSTEM_USER`].
+    pub fn is_system_user(&self) -> bool {
+        self.user == SYSTEM_USER
+    }
+
     /// Returns the value of the `application_name` configuration parameter.
     pub fn application_name(&self) -> &str {
         self.application_name.value()
@@ -255,6 +403,11 @@ impl Session {
         *self.extra_float_digits.value()
     }
 
+    /// Returns the value of the `max_cross_join_rows` configuration parameter.
+    pub fn max_cross_join_rows(&self) -> i32 {
+        *self.max_cross_join_rows.value()
+    }
+
     /// Returns the value of the `search_path` configuration parameter.
     pub fn search_path(&self) -> &'static [&'static str] {
         self.search_path.value
@@ -265,11 +418,34 @@ impl Session {
         self.server_version.value
     }
 
+    /// Returns the value of the `server_version_num` configuration parameter.
+    pub fn server_version_num(&self) -> &'static str {
+        self.server_version_num.value
+    }
+
     /// Returns the value of the `sql_safe_updates` configuration parameter.
     pub fn sql_safe_updates(&self) -> bool {
         *self.sql_safe_updates.value()
     }
 
+    /// Returns the value of the `standard_conforming_strings` configuration
+    /// parameter.
+    pub fn standard_conforming_strings(&self) -> &'static str {
+        self.standard_conforming_strings.value
+    }
+
+    /// Returns the UTC offset described by the `TimeZone` configuration
+    /// parameter.
+    pub fn timezone(&self) -> FixedOffset {
+        parse_timezone(self.timezone.value()).expect("TimeZone validated when set")
+    }
+
+    /// Returns the value of the `transaction_isolation` configuration
+    /// parameter.
+    pub fn transaction_isolation(&self) -> &'static str {
+        self.transaction_isolation.value
+    }
+
     /// Put the session into a transaction
     ///
     /// This does not nest, it just keeps us in a transaction even if we were already in
@@ -375,6 +551,40 @@ impl Session {
     }
 }
 
+/// Parses the value of the `TimeZone` configuration parameter.
+///
+/// Only `UTC` (or its alias `GMT`) and fixed offsets of the form `+HH`,
+/// `+HH:MM`, `-HH`, or `-HH:MM` are supported, since there is no time zone
+/// database to consult. Offsets follow the POSIX convention, as they do in
+/// PostgreSQL: positive offsets are *west* of Greenwich, so `+05:30` describes
+/// the zone whose UTC offset is -05:30.
+fn parse_timezone(s: &str) -> Result<FixedOffset, failure::Error> {
+    let invalid = || format_err!("invalid value for parameter TimeZone: {}", s);
+    if s.eq_ignore_ascii_case("UTC") || s.eq_ignore_ascii_case("GMT") {
+        return Ok(FixedOffset::east(0));
+    }
+    let (sign, rest) = match s.chars().next() {
+        Some('+') => (1, &s[1..]),
+        Some('-') => (-1, &s[1..]),
+        _ => return Err(invalid()),
+    };
+    let mut parts = rest.splitn(2, ':');
+    let hours = parts.next().unwrap_or("");
+    let minutes = parts.next().unwrap_or("0");
+    let valid = |part: &str| {
+        !part.is_empty() && part.len() <= 2 && part.chars().all(|c| c.is_ascii_digit())
+    };
+    if !valid(hours) || !valid(minutes) {
+        return Err(invalid());
+    }
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+    if hours > 15 || minutes > 59 {
+        return Err(invalid());
+    }
+    Ok(FixedOffset::west(sign * (hours * 3600 + minutes * 60)))
+}
+
 /// A trait for a session that exposes only the parameters that should impact
 /// the planning of a SQL query.
 pub trait PlanSession: fmt::Debug {
@@ -31,11 +31,14 @@ use expr::GlobalId;
 use interchange::{avro, protobuf};
 use ore::collections::CollectionExt;
 use ore::future::MaybeFuture;
+use ore::units::ByteSize;
 use repr::strconv;
-use repr::{ColumnType, Datum, RelationDesc, RelationType, Row, RowArena, ScalarType};
+use repr::{
+    ColumnType, CompatibilityMode, Datum, RelationDesc, RelationType, Row, RowArena, ScalarType,
+};
 use sql_parser::ast::{
-    AvroSchema, Connector, CsrSeed, Format, Ident, ObjectName, ObjectType, Query, SetVariableValue,
-    ShowStatementFilter, Stage, Statement, Value,
+    AvroSchema, Connector, CsrSeed, DiscardTarget, Format, Ident, ObjectName, ObjectType, Query,
+    SetVariableValue, ShowStatementFilter, Stage, Statement, Value,
 };
 
 use crate::expr::like::build_like_regex_from_string;
@@ -60,7 +63,10 @@ pub fn describe_statement(
         | Statement::SetVariable { .. }
         | Statement::StartTransaction { .. }
         | Statement::Rollback { .. }
-        | Statement::Commit { .. } => (None, vec![]),
+        | Statement::Commit { .. }
+        | Statement::Discard { .. }
+        | Statement::AlterSystemSet { .. }
+        | Statement::AlterSystemReset { .. } => (None, vec![]),
 
         Statement::Explain { stage, .. } => (
             Some(RelationDesc::empty().add_column(
@@ -257,7 +263,16 @@ fn handle_sync_statement(
         } => handle_show_columns(scx, extended, full, table_name, filter.as_ref()),
         Statement::ShowCreateView { view_name } => handle_show_create_view(scx, view_name),
         Statement::ShowCreateSource { source_name } => handle_show_create_source(scx, source_name),
-        Statement::Explain { stage, query } => handle_explain(scx, stage, *query, params),
+        Statement::Explain {
+            stage,
+            query,
+            redacted,
+        } => handle_explain(scx, stage, *query, redacted, params),
+        Statement::Discard { target } => handle_discard(target),
+        Statement::AlterSystemSet { variable, value } => {
+            handle_alter_system(scx, variable, Some(value))
+        }
+        Statement::AlterSystemReset { variable } => handle_alter_system(scx, variable, None),
 
         _ => bail!("unsupported SQL statement: {:?}", stmt),
     }
@@ -333,6 +348,35 @@ fn handle_rollback_transaction() -> Result<Plan, failure::Error> {
     Ok(Plan::Rollback)
 }
 
+fn handle_discard(target: DiscardTarget) -> Result<Plan, failure::Error> {
+    Ok(Plan::Discard(target))
+}
+
+fn handle_alter_system(
+    _: &StatementContext,
+    variable: Ident,
+    value: Option<SetVariableValue>,
+) -> Result<Plan, failure::Error> {
+    if variable.value != unicase::Ascii::new("maintenance_mode") {
+        bail!(
+            "unrecognized system configuration parameter \"{}\"",
+            variable
+        );
+    }
+    let message = match value {
+        None => None,
+        Some(SetVariableValue::Ident(ident)) if ident.value == unicase::Ascii::new("DEFAULT") => {
+            None
+        }
+        Some(SetVariableValue::Literal(Value::SingleQuotedString(s))) if !s.is_empty() => Some(s),
+        Some(value) => bail!(
+            "maintenance_mode must be set to a non-empty message, not {}",
+            value
+        ),
+    };
+    Ok(Plan::SetMaintenanceMode { message })
+}
+
 fn handle_show_databases(
     scx: &StatementContext,
     filter: Option<&ShowStatementFilter>,
@@ -657,20 +701,66 @@ fn handle_create_sink(scx: &StatementContext, stmt: Statement) -> Result<Plan, f
         _ => unreachable!(),
     };
 
-    let (mut broker, topic) = match connector {
+    let (mut broker, topic, compression, batch_size, linger) = match connector {
         Connector::File { .. } => bail!("file sinks are not yet supported"),
         Connector::Kafka {
             broker,
             topic,
             with_options,
         } => {
+            let mut with_options: HashMap<_, _> = with_options
+                .iter()
+                .map(|op| (op.name.value.to_ascii_lowercase(), op.value.clone()))
+                .collect();
+            let compression = match with_options.remove("compression") {
+                None => None,
+                Some(Value::SingleQuotedString(c)) => {
+                    let c = c.to_ascii_lowercase();
+                    match c.as_str() {
+                        "none" | "gzip" | "snappy" | "lz4" | "zstd" => Some(c),
+                        _ => bail!(
+                            "compression must be one of none, gzip, snappy, lz4, or zstd, \
+                             not '{}'",
+                            c
+                        ),
+                    }
+                }
+                Some(_) => bail!("compression must be a string"),
+            };
+            let batch_size = match with_options.remove("batch_size") {
+                None => None,
+                // The bounds are librdkafka's limits for batch.num.messages.
+                Some(Value::Number(n)) => match n.parse::<u32>() {
+                    Ok(n) if n > 0 && n <= 1_000_000 => Some(n),
+                    _ => bail!(
+                        "batch_size must be an integer between 1 and 1000000, not {}",
+                        n
+                    ),
+                },
+                Some(_) => bail!("batch_size must be a number"),
+            };
+            let linger = match with_options.remove("linger") {
+                None => None,
+                Some(Value::SingleQuotedString(d)) => {
+                    // librdkafka's queue.buffering.max.ms has millisecond
+                    // granularity and an upper bound of 900000ms.
+                    let linger = ore::units::parse_duration(&d)?;
+                    if linger.subsec_nanos() % 1_000_000 != 0 {
+                        bail!("linger must be a whole number of milliseconds, not '{}'", d);
+                    }
+                    if linger.as_millis() > 900_000 {
+                        bail!("linger must be at most 900000ms, not '{}'", d);
+                    }
+                    Some(linger)
+                }
+                Some(_) => bail!("linger must be a string"),
+            };
             if !with_options.is_empty() {
-                bail!(
-                    "Unexpected WITH options: {}",
-                    join(with_options.into_iter().map(|o| o.name.value), ",")
-                )
+                let mut keys: Vec<_> = with_options.keys().collect();
+                keys.sort();
+                bail!("Unexpected WITH options: {}", join(keys, ","))
             }
-            (broker, topic)
+            (broker, topic, compression, batch_size, linger)
         }
         Connector::Kinesis { .. } => bail!("Kinesis sinks are not yet supported"),
     };
@@ -699,6 +789,8 @@ fn handle_create_sink(scx: &StatementContext, stmt: Statement) -> Result<Plan, f
     let relation_desc = catalog_entry.desc()?.clone();
     let _ = interchange::avro::encode_schema(&relation_desc)?;
 
+    let real_time = uses_only_real_time_sources(scx.catalog, catalog_entry.id());
+
     let sink = Sink {
         create_sql,
         from: catalog_entry.id(),
@@ -706,6 +798,10 @@ fn handle_create_sink(scx: &StatementContext, stmt: Statement) -> Result<Plan, f
             url,
             topic,
             schema_registry_url: schema_registry_url.parse()?,
+            compression,
+            batch_size,
+            linger,
+            real_time,
         }),
     };
 
@@ -716,6 +812,31 @@ fn handle_create_sink(scx: &StatementContext, stmt: Statement) -> Result<Plan, f
     })
 }
 
+/// Reports whether `id` transitively depends upon at least one source, and
+/// every such source is an external source with real-time consistency.
+fn uses_only_real_time_sources(catalog: &Catalog, id: GlobalId) -> bool {
+    fn visit(catalog: &Catalog, id: GlobalId, found: &mut bool) -> bool {
+        let entry = catalog.get_by_id(&id);
+        match entry.item() {
+            CatalogItem::Source(catalog::Source {
+                connector:
+                    SourceConnector::External {
+                        consistency: Consistency::RealTime,
+                        ..
+                    },
+                ..
+            }) => {
+                *found = true;
+                true
+            }
+            CatalogItem::Source(_) => false,
+            _ => entry.uses().into_iter().all(|id| visit(catalog, id, found)),
+        }
+    }
+    let mut found = false;
+    visit(catalog, id, &mut found) && found
+}
+
 fn handle_create_index(scx: &StatementContext, stmt: Statement) -> Result<Plan, failure::Error> {
     let create_sql = normalize::create_statement(scx, stmt.clone())?;
     let (name, on_name, key_parts, if_not_exists) = match stmt {
@@ -796,7 +917,8 @@ fn handle_create_view(
     params: &Params,
 ) -> Result<Plan, failure::Error> {
     let create_sql = normalize::create_statement(scx, stmt.clone())?;
-    let (name, columns, query, materialized, replace, with_options) = match &mut stmt {
+    let (name, columns, query, materialized, replace, with_options, if_not_exists) = match &mut stmt
+    {
         Statement::CreateView {
             name,
             columns,
@@ -804,7 +926,16 @@ fn handle_create_view(
             materialized,
             replace,
             with_options,
-        } => (name, columns, query, materialized, replace, with_options),
+            if_not_exists,
+        } => (
+            name,
+            columns,
+            query,
+            materialized,
+            replace,
+            with_options,
+            *if_not_exists,
+        ),
         _ => unreachable!(),
     };
     if !with_options.is_empty() {
@@ -846,6 +977,18 @@ fn handle_create_view(
             desc.set_name(i, Some(normalize::column_name(name.clone())));
         }
     }
+    if let Some(id) = replace {
+        // Anything that reads the view under its old description must be able
+        // to read the rows that the replacement produces.
+        let old_desc = scx.catalog.get_by_id(&id).desc()?;
+        if let Err(errors) = desc.is_compatible_with(old_desc, CompatibilityMode::Forward) {
+            bail!(
+                "cannot replace view {} with an incompatible definition: {}",
+                name,
+                join(errors, ", ")
+            );
+        }
+    }
     let materialize = *materialized; // Normalize for `raw_sql` below.
     Ok(Plan::CreateView {
         name,
@@ -856,6 +999,7 @@ fn handle_create_view(
         },
         replace,
         materialize,
+        if_not_exists,
     })
 }
 
@@ -960,6 +1104,25 @@ fn handle_create_dataflow_pure(
                         Some(_) => bail!("ssl_certificate_file must be a string"),
                     };
 
+                    let max_message_size = match with_options.remove("max_message_size") {
+                        None => None,
+                        Some(Value::SingleQuotedString(s)) => {
+                            // librdkafka cannot fetch more than 1GB at a time.
+                            let max = s.parse::<ByteSize>()?.as_bytes();
+                            if max > 1_000_000_000 {
+                                bail!("max_message_size must be at most 1GB, not '{}'", s);
+                            }
+                            Some(max)
+                        }
+                        Some(_) => bail!("max_message_size must be a string"),
+                    };
+
+                    let append_only = match with_options.remove("append_only") {
+                        None => false,
+                        Some(Value::Boolean(b)) => b,
+                        Some(_) => bail!("append_only must be a boolean"),
+                    };
+
                     if !with_options.is_empty() {
                         bail!(
                             "Unexpected WITH options: {}",
@@ -974,6 +1137,8 @@ fn handle_create_dataflow_pure(
                         envelope,
                         consistency,
                         ssl_certificate_file,
+                        max_message_size,
+                        append_only,
                     )?
                 }
                 Connector::Kinesis { arn, with_options } => {
@@ -1131,7 +1296,17 @@ fn handle_create_dataflow_pure(
                                 RelationDesc::new(RelationType::new(cols), names),
                             )
                         }
-                        Format::Json => bail!("JSON-format file sources are not yet supported"),
+                        Format::Json => (
+                            DataEncoding::Json,
+                            RelationDesc::new(
+                                RelationType::new(vec![
+                                    ColumnType::new(ScalarType::Jsonb),
+                                    ColumnType::new(ScalarType::Int64).nullable(true),
+                                ]),
+                                iter::once(Some(String::from("data")))
+                                    .chain(iter::once(Some(String::from("mz_line_no")))),
+                            ),
+                        ),
                         Format::Text => (
                             DataEncoding::Text,
                             RelationDesc::new(
@@ -1375,18 +1550,25 @@ fn handle_explain(
     scx: &StatementContext,
     stage: Stage,
     query: Query,
+    redacted: bool,
     params: &Params,
 ) -> Result<Plan, failure::Error> {
-    let (relation_expr, _desc, _finishing) =
+    let (mut relation_expr, _desc, _finishing) =
         handle_query(scx, query, params, QueryLifetime::OneShot)?;
     // Previouly we would bail here for ORDER BY and LIMIT; this has been relaxed to silently
     // report the plan without the ORDER BY and LIMIT decorations (which are done in post).
     if stage == Stage::Dataflow {
+        if redacted {
+            relation_expr.redact_literals();
+        }
         Ok(Plan::SendRows(vec![Row::pack(&[Datum::String(
             &relation_expr.pretty_humanized(scx.catalog),
         )])]))
     } else {
-        Ok(Plan::ExplainPlan(relation_expr))
+        Ok(Plan::ExplainPlan {
+            relation_expr,
+            redacted,
+        })
     }
 }
 
@@ -1410,11 +1592,19 @@ fn build_kafka_source(
     envelope: Envelope,
     consistency: Consistency,
     ssl_certificate_file: Option<PathBuf>,
+    max_message_size: Option<u64>,
+    append_only: bool,
 ) -> Result<Source, failure::Error> {
     match (format, envelope) {
-        (Format::Avro(schema), Envelope::Debezium) => {
-            build_kafka_avro_source(schema, url, topic, consistency, ssl_certificate_file)
-        }
+        (Format::Avro(schema), Envelope::Debezium) => build_kafka_avro_source(
+            schema,
+            url,
+            topic,
+            consistency,
+            ssl_certificate_file,
+            max_message_size,
+            append_only,
+        ),
         (Format::Avro(_), _) => {
             // TODO(brennan) -- there's no reason not to support this
             bail!("Currently, only Avro in Debezium-envelope format is supported")
@@ -1432,6 +1622,8 @@ fn build_kafka_source(
             message_name,
             consistency,
             ssl_certificate_file,
+            max_message_size,
+            append_only,
         ),
         (Format::Protobuf { .. }, Envelope::Debezium) => {
             bail!("Currently, Debezium-style envelopes are not supported for protobuf messages.")
@@ -1475,6 +1667,8 @@ fn build_kafka_avro_source(
     topic: String,
     consistency: Consistency,
     ssl_certificate_file: Option<PathBuf>,
+    max_message_size: Option<u64>,
+    append_only: bool,
 ) -> Result<Source, failure::Error> {
     let Schema {
         key_schema,
@@ -1518,6 +1712,8 @@ fn build_kafka_avro_source(
                 url: kafka_url,
                 topic,
                 ssl_certificate_file,
+                max_message_size,
+                append_only,
             }),
             encoding: DataEncoding::Avro(AvroEncoding {
                 raw_schema: value_schema,
@@ -1537,6 +1733,8 @@ fn build_kafka_protobuf_source(
     message_name: &str,
     consistency: Consistency,
     ssl_certificate_file: Option<PathBuf>,
+    max_message_size: Option<u64>,
+    append_only: bool,
 ) -> Result<Source, failure::Error> {
     let descriptors = match schema {
         sql_parser::ast::Schema::Inline(bytes) => strconv::parse_bytes(&bytes)?,
@@ -1555,6 +1753,8 @@ fn build_kafka_protobuf_source(
                 url,
                 topic,
                 ssl_certificate_file,
+                max_message_size,
+                append_only,
             }),
             encoding: DataEncoding::Protobuf(ProtobufEncoding {
                 descriptors,
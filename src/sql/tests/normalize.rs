@@ -0,0 +1,49 @@
+// Copyright Materialize, Inc. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::error::Error;
+
+use catalog::{BincodeSerializer, Catalog};
+use ore::collections::CollectionExt;
+use sql::normalize;
+use sql::{Session, StatementContext};
+
+#[test]
+fn test_verify_rewrite() -> Result<(), Box<dyn Error>> {
+    let catalog = Catalog::open::<BincodeSerializer, _>(None, |_| ())?;
+    let session = Session::default();
+    let scx = StatementContext {
+        catalog: &catalog,
+        session: &session,
+    };
+
+    // The output of the normalizer is accepted.
+    let stmt = sql::parse("CREATE VIEW v AS SELECT 1 AS a".into())?.into_element();
+    let create_sql = normalize::create_statement(&scx, stmt)?;
+    normalize::verify_rewrite(&scx, &create_sql)?;
+
+    // SQL that does not reparse is rejected.
+    let err = normalize::verify_rewrite(&scx, "CREATE VIEW").unwrap_err();
+    assert!(err.to_string().contains("does not reparse"), "{}", err);
+
+    // SQL that reparses as more than one statement is rejected.
+    let two = format!("{}; {}", create_sql, create_sql);
+    let err = normalize::verify_rewrite(&scx, &two).unwrap_err();
+    assert!(
+        err.to_string().contains("reparsed as 2 statements"),
+        "{}",
+        err
+    );
+
+    // SQL that changes when normalized again is rejected.
+    let err = normalize::verify_rewrite(&scx, "CREATE VIEW v AS SELECT 1 AS a").unwrap_err();
+    assert!(err.to_string().contains("is not stable"), "{}", err);
+
+    Ok(())
+}
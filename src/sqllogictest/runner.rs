@@ -382,6 +382,7 @@ impl State {
             data_directory: None,
             executor: &executor,
             timestamp: None,
+            read_only: false,
         })?;
 
         let coord_thread = thread::spawn(move || coord.serve(cmd_rx)).join_on_drop();